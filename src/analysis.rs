@@ -0,0 +1,156 @@
+use rand::{RngCore, SeedableRng};
+
+use super::*;
+
+/// Measurements of how well a shuffle randomizes a deck, taken over many trials. Each trial
+/// shuffles the cards of the same deck, so every measure compares the shuffled order with the
+/// original order. Where a measure has an expected value for a perfectly random shuffle, it is
+/// given alongside.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShuffleReport {
+    /// The number of trials
+    pub trials: usize,
+    /// The number of cards in the deck
+    pub cards: usize,
+    /// The mean number of rising sequences, i.e. runs of cards still in their original relative
+    /// order
+    pub mean_rising_sequences: f64,
+    /// The mean number of rising sequences of a random order, (n + 1) / 2 for n cards
+    pub expected_rising_sequences: f64,
+    /// The chi-square statistic of how often each card ended in each position
+    pub position_chi_square: f64,
+    /// The degrees of freedom of the chi-square statistic, (n - 1)^2 for n cards. A random
+    /// shuffle gives a statistic close to this.
+    pub position_degrees_of_freedom: usize,
+    /// The proportion of cards which are still followed by the card which originally followed them
+    pub adjacent_pair_survival: f64,
+    /// The proportion of adjacent pairs which survive in a random order, 1 / n for n cards
+    pub expected_adjacent_pair_survival: f64,
+    /// An estimate of the total variation distance between the number of rising sequences and
+    /// that of a random order, from 0 for a random order to 1 for a deck which is barely shuffled
+    pub total_variation_distance: f64,
+}
+
+/// Returns the number of rising sequences in the shuffled cards, i.e. the fewest runs of cards in
+/// their original relative order which the shuffled cards can be split into. Cards which are not
+/// in the original cards are ignored.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, rising_sequences};
+/// let original = hand!("2S", "3S", "4S", "5S");
+/// let shuffled = hand!("2S", "4S", "3S", "5S");
+/// assert_eq!(rising_sequences(original.cards(), shuffled.cards()), 2);
+/// # }
+/// ```
+pub fn rising_sequences(original: &[Card], shuffled: &[Card]) -> usize {
+    let positions = positions(shuffled);
+    let positions: Vec<usize> = original.iter().filter_map(|c| positions[c.ordinal()]).collect();
+    if positions.is_empty() {
+        0
+    } else {
+        1 + positions.windows(2).filter(|w| w[1] < w[0]).count()
+    }
+}
+
+/// Returns the position of each card indexed by its ordinal
+fn positions(cards: &[Card]) -> [Option<usize>; 56] {
+    let mut positions = [None; 56];
+    cards.iter().enumerate().for_each(|(i, c)| positions[c.ordinal()] = Some(i));
+    positions
+}
+
+/// Returns the proportion of random orders of the number of cards which have each number of
+/// rising sequences, i.e. the Eulerian numbers divided by n!. The first entry is for one rising
+/// sequence.
+fn eulerian_distribution(n: usize) -> Vec<f64> {
+    // p[k] is the proportion of orders with k descents, i.e. k + 1 rising sequences
+    let mut p = vec![1.0];
+    for m in 2..=n {
+        let mut next = vec![0.0; m];
+        for (k, value) in next.iter_mut().enumerate() {
+            let stay = p.get(k).map_or(0.0, |p| (k + 1) as f64 * p);
+            let step = if k > 0 { p.get(k - 1).map_or(0.0, |p| (m - k) as f64 * p) } else { 0.0 };
+            *value = (stay + step) / m as f64;
+        }
+        p = next;
+    }
+    p
+}
+
+/// Runs the shuffle function over a copy of the deck for the number of trials and measures how
+/// well it randomizes the undealt cards. The deck must not hold the same card twice. The copy's
+/// cards are put back to those of the deck before every trial but its RNG is not, so a shuffle
+/// with the deck's own RNG carries on from one trial to the next.
+///
+/// The total variation distance only looks at the number of rising sequences. This is exact for
+/// riffle shuffles and a lower bound otherwise, and a small number of trials adds some noise so
+/// even a perfect shuffle does not measure 0.
+///
+/// # Example
+///
+/// ```
+/// use deckofcards::{Cards, Deck, analyze_shuffle};
+/// use rand::SeedableRng;
+/// use rand_pcg::Pcg32;
+/// let mut rng = Pcg32::seed_from_u64(1);
+/// let one_riffle = analyze_shuffle(&Deck::new(), 100, |d| d.riffle_shuffle(&mut rng)).unwrap();
+/// assert!(one_riffle.mean_rising_sequences <= 2.0);
+/// assert!(one_riffle.total_variation_distance > 0.99);
+/// ```
+pub fn analyze_shuffle<R, F>(deck: &Deck<R>, trials: usize, mut shuffle: F) -> Result<ShuffleReport, AnalysisError>
+    where R: RngCore + SeedableRng + Clone, F: FnMut(&mut Deck<R>) {
+    let original = deck.cards();
+    let n = original.len();
+    if trials == 0 {
+        return Err(AnalysisError::NoTrials);
+    }
+    if n < 2 {
+        return Err(AnalysisError::TooFewCards { found: n });
+    }
+    let index = positions(original);
+    if index.iter().flatten().count() != n {
+        return Err(AnalysisError::DuplicateCard);
+    }
+
+    let mut position_counts = vec![0usize; n * n];
+    let mut rising_counts = vec![0usize; n];
+    let mut rising_total = 0;
+    let mut surviving_pairs = 0;
+    let mut shuffled = deck.clone();
+    for _ in 0..trials {
+        shuffled.copy_cards_from(deck);
+        shuffle(&mut shuffled);
+        let cards = shuffled.cards();
+        if cards.len() != n || cards.iter().any(|c| index[c.ordinal()].is_none()) {
+            return Err(AnalysisError::CardsChanged);
+        }
+        for (position, card) in cards.iter().enumerate() {
+            position_counts[index[card.ordinal()].unwrap() * n + position] += 1;
+        }
+        surviving_pairs += cards.windows(2).filter(|w| index[w[1].ordinal()] == index[w[0].ordinal()].map(|i| i + 1)).count();
+        let rising = rising_sequences(original, cards);
+        rising_counts[rising - 1] += 1;
+        rising_total += rising;
+    }
+
+    let expected = trials as f64 / n as f64;
+    let position_chi_square = position_counts.iter().map(|o| (*o as f64 - expected).powi(2) / expected).sum();
+    let total_variation_distance = rising_counts.iter().zip(eulerian_distribution(n))
+        .map(|(count, p)| (*count as f64 / trials as f64 - p).abs())
+        .sum::<f64>() / 2.0;
+    Ok(ShuffleReport {
+        trials,
+        cards: n,
+        mean_rising_sequences: rising_total as f64 / trials as f64,
+        expected_rising_sequences: (n + 1) as f64 / 2.0,
+        position_chi_square,
+        position_degrees_of_freedom: (n - 1) * (n - 1),
+        adjacent_pair_survival: surviving_pairs as f64 / ((n - 1) * trials) as f64,
+        expected_adjacent_pair_survival: 1.0 / n as f64,
+        total_variation_distance,
+    })
+}
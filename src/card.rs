@@ -3,24 +3,35 @@ extern crate rand;
 use std::cmp::Ordering;
 use std::fmt;
 use std::slice::Iter;
+use std::str::FromStr;
 
 use super::*;
 
 /// A `Card` has a `Rank` and a `Suit` and represents a card from the normal 52-card
 /// playing deck.
 ///
+/// A card may also be a Joker, which has the `Rank::Joker` rank. A Joker is made with a suit which
+/// tells it apart from other Jokers in the same deck and gives its colour, so a Joker of Spades or
+/// Clubs is a black Joker and a Joker of Hearts or Diamonds is a red Joker. A Joker does not belong
+/// to that suit though, so `suit()` is `None` for it, `is_spades()` and the other suit tests are
+/// false and sorting by suit puts it after the suited cards. In short form a Joker is written with
+/// an 'X' for its rank, e.g. "XH" for the red Joker.
+///
 /// # Example
 ///
 /// ```
 /// use deckofcards::{Card, Rank, Suit};
 /// let card = Card::new(Rank::Jack, Suit::Hearts);
+/// assert_eq!(card.rank(), Rank::Jack);
+/// assert_eq!(card.suit(), Some(Suit::Hearts));
+/// assert_eq!(Card::red_joker().suit(), None);
 /// ```
-#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Debug)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub struct Card {
     /// The card's `Rank`, e.g. Jack
-    pub rank: Rank,
-    /// The card's `Suit`, e.g. Hearts
-    pub suit: Suit,
+    pub(crate) rank: Rank,
+    /// The card's `Suit`, e.g. Hearts, or for a Joker the suit it was made with
+    pub(crate) suit: Suit,
 }
 
 impl fmt::Display for Card {
@@ -29,6 +40,23 @@ impl fmt::Display for Card {
     }
 }
 
+impl FromStr for Card {
+    type Err = ParseError;
+
+    /// Creates a card from a string, e.g. "AS" returns Ace of Spades. Parsing is lenient so the
+    /// suit may be lowercase or unicode and may come first, Ten may be "10" and cards may be named,
+    /// e.g. "As", "10H", "A♠", "♠A", "Ace of Spades" or "ten of hearts".
+    fn from_str(s: &str) -> Result<Card, ParseError> {
+        parse::parse_card(s)
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Card) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Ord for Card {
     /// Sorts by rank and then suit
     fn cmp(&self, other: &Card) -> Ordering {
@@ -45,6 +73,20 @@ impl Card {
         }
     }
 
+    /// Returns the card's `Rank`, e.g. Jack
+    pub fn rank(&self) -> Rank {
+        self.rank
+    }
+
+    /// Returns the card's `Suit`, e.g. Hearts, or `None` for a Joker which is not of any suit
+    pub fn suit(&self) -> Option<Suit> {
+        if self.is_joker() {
+            None
+        } else {
+            Some(self.suit)
+        }
+    }
+
     /// Compares by rank and then suit
     pub fn cmp_rank_then_suit(&self, other: &Card) -> Ordering {
         let result: Ordering = self.rank.cmp(&other.rank);
@@ -64,84 +106,140 @@ impl Card {
         result
     }
 
-    /// Compares by suit and then rank
+    /// Compares by suit, where Jokers come after every suited card, e.g. Spades, Hearts, Diamonds,
+    /// Clubs and then Jokers
+    fn cmp_suit(&self, other: &Card) -> Ordering {
+        self.is_joker().cmp(&other.is_joker()).then(self.suit.cmp(&other.suit))
+    }
+
+    /// Compares by suit and then rank. Jokers come after the suited cards.
     pub fn cmp_suit_then_rank(&self, other: &Card) -> Ordering {
-        let result: Ordering = self.suit.cmp(&other.suit);
+        let result: Ordering = self.cmp_suit(other);
         if result == Ordering::Equal {
             return self.rank.cmp(&other.rank);
         }
         result
     }
 
-    /// Compares by suit and then rank
+    /// Compares by suit and then descending rank. Jokers come after the suited cards.
     pub fn cmp_suit_then_desc_rank(&self, other: &Card) -> Ordering {
-        let result: Ordering = self.suit.cmp(&other.suit);
+        let result: Ordering = self.cmp_suit(other);
         if result == Ordering::Equal {
             return self.rank.cmp(&other.rank).reverse();
         }
         result
     }
 
-    /// Creates a card from a string such, e.g. "AS" returns Ace of Spades
-    pub fn from_str(s: &str) -> Result<Card, &'static str> {
-        if s.len() != 2 {
-            return Err("String is wrong length");
+    /// Creates a card from a string, e.g. "AS" returns Ace of Spades. This is kept for callers which
+    /// do not import `FromStr` and parses in the same way as `Card::from_str()` from the trait.
+    #[deprecated(since = "0.5.0", note = "use `str::parse` or `FromStr::from_str` instead")]
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Card, ParseError> {
+        <Card as FromStr>::from_str(s)
+    }
+
+    /// Turns the card into a short string consisting of rank, suit, e.g. "AS"
+    pub fn to_str(&self) -> String {
+        format!("{}{}", self.rank.to_char(), self.suit.to_char())
+    }
+
+    /// Returns an English formatted name of the card, e.g. "Ace of Spades" or "Red Joker"
+    pub fn name(&self) -> String {
+        if self.is_joker() {
+            let colour = if self.is_red() { "Red" } else { "Black" };
+            format!("{} {}", colour, self.rank.to_str())
+        } else {
+            format!("{} of {}", self.rank.to_str(), self.suit.to_str())
         }
+    }
 
-        let s = s.to_string();
-        let mut i = s.chars();
-        let c1 = i.next().unwrap();
-        let c2 = i.next().unwrap();
+    /// Returns an ordinal for the card which is a unique number which can be used for indexing.
+    /// The standard cards are 0 to 51 and Jokers are 52 to 55.
+    pub fn ordinal(&self) -> usize {
+        if self.is_joker() {
+            52 + self.suit.ordinal()
+        } else {
+            self.suit.ordinal() * 13 + self.rank.ordinal()
+        }
+    }
 
-        // Test rank / suit
-        if let Ok(rank) = Rank::from_char(c1) {
-            if let Ok(suit) = Suit::from_char(c2) {
-                return Ok(Card::new(rank, suit));
-            }
+    /// Returns the card for an ordinal produced by `ordinal()`, or `None` if the ordinal is out of range
+    pub fn from_ordinal(ordinal: usize) -> Option<Card> {
+        if ordinal < 52 {
+            Card::all_cards().get(ordinal).copied()
+        } else {
+            Card::jokers().get(ordinal - 52).copied()
         }
-        // Try suit / rank
-        if let Ok(suit) = Suit::from_char(c1) {
-            if let Ok(rank) = Rank::from_char(c2) {
-                return Ok(Card::new(rank, suit));
-            }
+    }
+
+    /// Creates a Joker distinguished by the suit
+    pub fn joker(suit: Suit) -> Card {
+        Card::new(Rank::Joker, suit)
+    }
+
+    /// Creates the black Joker
+    pub fn black_joker() -> Card {
+        Card::joker(Suit::Spades)
+    }
+
+    /// Creates the red Joker
+    pub fn red_joker() -> Card {
+        Card::joker(Suit::Hearts)
+    }
+
+    /// Returns the glyph of the card from the Unicode Playing Cards block, e.g. '🂡' for the Ace of
+    /// Spades, see `CardGlyph`
+    pub fn to_unicode(&self) -> char {
+        CardGlyph::Card(*self).to_char()
+    }
+
+    /// Returns the card of a glyph from the Unicode Playing Cards block, or `None` if the char is
+    /// not the glyph of a card, see `CardGlyph`
+    pub fn from_unicode(ch: char) -> Option<Card> {
+        match CardGlyph::from_char(ch) {
+            Some(CardGlyph::Card(card)) => Some(card),
+            _ => None,
         }
+    }
 
-        Err("Invalid string")
+    /// Tests if the card is a Joker
+    pub fn is_joker(&self) -> bool {
+        self.rank.is_joker()
     }
 
-    /// Turns the card into a short string consisting of rank, suit, e.g. "AS"
-    pub fn to_str(&self) -> String {
-        format!("{}{}", self.rank.to_char(), self.suit.to_char())
+    /// Tests if the card is red, i.e. Hearts, Diamonds or a red Joker
+    pub fn is_red(&self) -> bool {
+        self.suit.is_red()
     }
 
-    /// Returns an English formatted name of the card, e.g. "Ace of Spades"
-    pub fn name(&self) -> String {
-        format!("{} of {}", self.rank.to_str(), self.suit.to_str())
+    /// Tests if the card is black, i.e. Spades, Clubs or a black Joker
+    pub fn is_black(&self) -> bool {
+        self.suit.is_black()
     }
 
-    /// Returns an ordinal for the card which is a unique number which can be used for indexing
-    pub fn ordinal(&self) -> usize {
-        self.suit.ordinal() * 13 + self.rank.ordinal()
+    /// Tests if the card is of the suit. A Joker is not of any suit.
+    pub(crate) fn is_suit(&self, suit: Suit) -> bool {
+        !self.is_joker() && self.suit == suit
     }
 
     /// Tests if the card is Hearts
     pub fn is_hearts(&self) -> bool {
-        self.suit == Suit::Hearts
+        self.is_suit(Suit::Hearts)
     }
 
     /// Tests if the card is Clubs
     pub fn is_clubs(&self) -> bool {
-        self.suit == Suit::Clubs
+        self.is_suit(Suit::Clubs)
     }
 
     /// Tests if the card is Spades
     pub fn is_spades(&self) -> bool {
-        self.suit == Suit::Spades
+        self.is_suit(Suit::Spades)
     }
 
     /// Tests if the card is Diamonds
     pub fn is_diamonds(&self) -> bool {
-        self.suit == Suit::Diamonds
+        self.is_suit(Suit::Diamonds)
     }
 
     /// Returns an array slice containing all the cards in a standard 52-card deck
@@ -203,7 +301,19 @@ impl Card {
         &CARDS
     }
 
+    /// Returns an array slice of the four distinct Jokers in order of their ordinal, starting
+    /// with the black and red Jokers
+    pub fn jokers() -> &'static [Card] {
+        static JOKERS: [Card; 4] = [
+            Card { suit: Suit::Spades, rank: Rank::Joker },
+            Card { suit: Suit::Hearts, rank: Rank::Joker },
+            Card { suit: Suit::Diamonds, rank: Rank::Joker },
+            Card { suit: Suit::Clubs, rank: Rank::Joker },
+        ];
+        &JOKERS
+    }
+
     pub fn iterator() -> Iter<'static, Card> {
-        Card::all_cards().into_iter()
+        Card::all_cards().iter()
     }
 }
@@ -0,0 +1,232 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+use rand::{RngCore, SeedableRng};
+
+use super::*;
+
+/// The bits of the standard 52 cards
+const ALL_BITS: u64 = (1 << 52) - 1;
+
+/// The bits of every card ordinal, i.e. the standard 52 cards and the four Jokers
+const ORDINAL_BITS: u64 = (1 << 56) - 1;
+
+/// A `CardSet` is a set of unique cards held as a bitmask where each bit is the `Card::ordinal()`
+/// of a card. Unlike a `Hand` it has no order and cannot hold the same card twice, but testing
+/// if a card is in the set and combining sets are very cheap.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::CardSet;
+/// let dead = CardSet::from(&hand!("AS", "KD"));
+/// let live = !dead;
+/// assert_eq!(live.len(), 50);
+/// assert!(!live.contains(&card!("AS")));
+/// # }
+/// ```
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Default, Debug)]
+pub struct CardSet(u64);
+
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_hand())
+    }
+}
+
+impl CardSet {
+    /// Creates an empty set
+    pub fn new() -> CardSet {
+        CardSet(0)
+    }
+
+    /// Creates a set of the standard 52 cards
+    pub fn all() -> CardSet {
+        CardSet(ALL_BITS)
+    }
+
+    /// Creates a set from a bitmask of card ordinals such as produced by `card_mask`. Bits 56 to
+    /// 63 are not the ordinal of any card and are ignored.
+    pub fn from_bits(bits: u64) -> CardSet {
+        CardSet(bits & ORDINAL_BITS)
+    }
+
+    /// Creates a set from a slice of cards. Duplicate cards are only held once.
+    pub fn from_cards(cards: &[Card]) -> CardSet {
+        CardSet(card_mask(cards))
+    }
+
+    /// Returns the bitmask of card ordinals, e.g. for `evaluate_mask`
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the number of cards in the set
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Tests if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Tests if the card is in the set
+    pub fn contains(&self, card: &Card) -> bool {
+        self.0 & (1 << card.ordinal()) != 0
+    }
+
+    /// Adds the card to the set, returning false if it was already in the set
+    pub fn insert(&mut self, card: Card) -> bool {
+        let present = self.contains(&card);
+        self.0 |= 1 << card.ordinal();
+        !present
+    }
+
+    /// Removes the card from the set, returning false if it was not in the set
+    pub fn remove(&mut self, card: &Card) -> bool {
+        let present = self.contains(card);
+        self.0 &= !(1 << card.ordinal());
+        present
+    }
+
+    /// Returns the cards in either set
+    pub fn union(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    /// Returns the cards in both sets
+    pub fn intersection(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    /// Returns the cards in this set but not the other
+    pub fn difference(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// Returns the standard cards which are not in the set
+    pub fn complement(&self) -> CardSet {
+        CardSet(!self.0 & ALL_BITS)
+    }
+
+    /// Returns an iterator through the cards in the set in order of their ordinal
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    /// Makes a `Hand` of the cards in order of their ordinal
+    pub fn to_hand(&self) -> Hand {
+        Hand { cards: self.iter().collect() }
+    }
+
+    /// Makes a `Deck` of the cards in order of their ordinal
+    pub fn to_deck(&self) -> Deck {
+        Deck::from_cards(&self.iter().collect::<Vec<Card>>())
+    }
+}
+
+/// An iterator through the cards of a `CardSet`
+#[derive(Clone, Debug)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let ordinal = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Card::from_ordinal(ordinal)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> CardSet {
+        let mut set = CardSet::new();
+        iter.into_iter().for_each(|c| { set.insert(c); });
+        set
+    }
+}
+
+impl From<&Hand> for CardSet {
+    fn from(hand: &Hand) -> CardSet {
+        CardSet::from_cards(hand.cards())
+    }
+}
+
+impl<R: RngCore + SeedableRng> From<&Deck<R>> for CardSet {
+    /// Makes a set of the undealt cards of the `Deck`
+    fn from(deck: &Deck<R>) -> CardSet {
+        CardSet::from_cards(deck.cards())
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, rhs: CardSet) -> CardSet {
+        self.union(&rhs)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, rhs: CardSet) {
+        *self = self.union(&rhs);
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, rhs: CardSet) -> CardSet {
+        self.intersection(&rhs)
+    }
+}
+
+impl BitAndAssign for CardSet {
+    fn bitand_assign(&mut self, rhs: CardSet) {
+        *self = self.intersection(&rhs);
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, rhs: CardSet) -> CardSet {
+        self.difference(&rhs)
+    }
+}
+
+impl SubAssign for CardSet {
+    fn sub_assign(&mut self, rhs: CardSet) {
+        *self = self.difference(&rhs);
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    fn not(self) -> CardSet {
+        self.complement()
+    }
+}
@@ -61,9 +61,9 @@ pub fn cards_of_rank(cards: &[Card], rank: Rank) -> Vec<Card> {
     cards.iter().filter(|c| c.rank == rank).cloned().collect()
 }
 
-/// Returns cards of the specified suit
+/// Returns cards of the specified suit. Jokers are not of any suit.
 pub fn cards_of_suit(cards: &[Card], suit: Suit) -> Vec<Card> {
-    cards.iter().filter(|c| c.suit == suit).cloned().collect()
+    cards.iter().filter(|c| c.is_suit(suit)).cloned().collect()
 }
 
 /// Perform a Knuth shuffle on a deck of cards using the given RNG
@@ -75,6 +75,133 @@ pub fn knuth_shuffle<T: Rng>(cards: &mut [Card], rng: &mut T) {
     }
 }
 
+/// The chance of a packet ending between any two cards in an overhand shuffle, so a packet is six
+/// cards on average
+const OVERHAND_CUT_PROBABILITY: f64 = 1.0 / 6.0;
+
+/// Perform a riffle shuffle on the cards using the Gilbert-Shannon-Reeds model. The cards are cut
+/// into two piles with the size of the top pile being binomially distributed, and the piles are
+/// riffled together by dropping a card from either pile with a chance proportional to the number of
+/// cards left in that pile.
+pub fn riffle_shuffle<T: Rng>(cards: &mut [Card], rng: &mut T) {
+    let n = cards.len();
+    let cut = (0..n).filter(|_| rng.gen_bool(0.5)).count();
+    let (bottom, top) = cards.split_at(cut);
+    let mut result = Vec::with_capacity(n);
+    let (mut b, mut t) = (0, 0);
+    while b < bottom.len() || t < top.len() {
+        let (b_left, t_left) = (bottom.len() - b, top.len() - t);
+        if rng.gen_range(0..b_left + t_left) < b_left {
+            result.push(bottom[b]);
+            b += 1;
+        } else {
+            result.push(top[t]);
+            t += 1;
+        }
+    }
+    cards.copy_from_slice(&result);
+}
+
+/// Reverses the order of the packets between the cut points while keeping the order of the cards
+/// in each packet, which is what happens when packets are taken one by one from the top of the
+/// cards and dropped onto a new pile.
+fn reverse_packets(cards: &mut [Card], cuts: &[usize]) {
+    let mut result = Vec::with_capacity(cards.len());
+    let mut end = cards.len();
+    for start in cuts.iter().rev().chain(std::iter::once(&0)) {
+        result.extend_from_slice(&cards[*start..end]);
+        end = *start;
+    }
+    cards.copy_from_slice(&result);
+}
+
+/// Perform an overhand shuffle on the cards using the given RNG. Small packets of cards, six on
+/// average, are taken from the top and dropped onto a new pile which reverses the order of the
+/// packets.
+pub fn overhand_shuffle<T: Rng>(cards: &mut [Card], rng: &mut T) {
+    let cuts: Vec<usize> = (1..cards.len()).filter(|_| rng.gen_bool(OVERHAND_CUT_PROBABILITY)).collect();
+    reverse_packets(cards, &cuts);
+}
+
+/// Perform a strip cut on the cards using the given RNG. The cards are stripped from the top into
+/// the number of packets of random size, which reverses the order of the packets.
+pub fn strip_cut_shuffle<T: Rng>(cards: &mut [Card], strips: usize, rng: &mut T) {
+    let positions = cards.len().saturating_sub(1);
+    let mut cuts: Vec<usize> = rand::seq::index::sample(rng, positions, strips.saturating_sub(1).min(positions))
+        .into_iter()
+        .map(|i| i + 1)
+        .collect();
+    cuts.sort_unstable();
+    reverse_packets(cards, &cuts);
+}
+
+/// Splits the items into two halves and perfectly interleaves them. The top of the items is the
+/// end of the slice. An out-faro keeps the top card on top, an in-faro moves it to second from
+/// the top. When there is an odd number, the half which goes on top has the extra item so the
+/// halves still alternate.
+fn faro<T: Copy>(items: &mut [T], out: bool) {
+    let from_top: Vec<T> = items.iter().rev().copied().collect();
+    let (top, bottom) = from_top.split_at(if out { items.len().div_ceil(2) } else { items.len() / 2 });
+    let (first, second) = if out { (top, bottom) } else { (bottom, top) };
+    let mut result = Vec::with_capacity(items.len());
+    for (i, item) in first.iter().enumerate() {
+        result.push(*item);
+        if let Some(item) = second.get(i) {
+            result.push(*item);
+        }
+    }
+    result.reverse();
+    items.copy_from_slice(&result);
+}
+
+/// Perform an out-faro shuffle, a perfect interleave of the two halves which keeps the top card,
+/// and the bottom card of an even number of cards, in place
+pub fn out_faro(cards: &mut [Card]) {
+    faro(cards, true);
+}
+
+/// Perform an in-faro shuffle, a perfect interleave of the two halves which moves the top card
+/// to second from the top
+pub fn in_faro(cards: &mut [Card]) {
+    faro(cards, false);
+}
+
+/// Cuts the cards by moving the number of cards from the top to the bottom. A cut of more cards
+/// than there are wraps around.
+pub fn cut(cards: &mut [Card], n: usize) {
+    if !cards.is_empty() {
+        let n = n % cards.len();
+        cards.rotate_right(n);
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Returns the number of out-faro shuffles which restore the number of cards to their original
+/// order, e.g. 8 for a standard 52 card deck
+pub fn out_faro_restore_count(n: usize) -> usize {
+    // The answer is the least common multiple of the cycle lengths of one out-faro
+    let mut positions: Vec<usize> = (0..n).collect();
+    faro(&mut positions, true);
+    let mut visited = vec![false; n];
+    let mut count = 1;
+    for start in 0..n {
+        let mut length = 0;
+        let mut i = start;
+        while !visited[i] {
+            visited[i] = true;
+            i = positions[i];
+            length += 1;
+        }
+        if length > 0 {
+            count = count / gcd(count, length) * length;
+        }
+    }
+    count
+}
+
 /// Certain actions are common to a deck and a hand of cards
 pub trait Cards {
     /// Return the cards as a slice
@@ -88,12 +215,49 @@ pub trait Cards {
         knuth_shuffle(self.mut_cards(), rng);
     }
 
-    /// Shuffle the cards into a random order
+    /// Perform a riffle shuffle with the given RNG, see `riffle_shuffle`
+    fn riffle_shuffle<T: Rng>(&mut self, rng: &mut T) {
+        riffle_shuffle(self.mut_cards(), rng);
+    }
+
+    /// Perform an overhand shuffle with the given RNG, see `overhand_shuffle`
+    fn overhand_shuffle<T: Rng>(&mut self, rng: &mut T) {
+        overhand_shuffle(self.mut_cards(), rng);
+    }
+
+    /// Perform a strip cut into the number of packets with the given RNG, see `strip_cut_shuffle`
+    fn strip_cut_shuffle<T: Rng>(&mut self, strips: usize, rng: &mut T) {
+        strip_cut_shuffle(self.mut_cards(), strips, rng);
+    }
+
+    /// Perform an out-faro shuffle, see `out_faro`
+    fn out_faro(&mut self) {
+        out_faro(self.mut_cards());
+    }
+
+    /// Perform an in-faro shuffle, see `in_faro`
+    fn in_faro(&mut self) {
+        in_faro(self.mut_cards());
+    }
+
+    /// Cuts the number of cards from the top to the bottom, see `cut`
+    fn cut(&mut self, n: usize) {
+        cut(self.mut_cards(), n);
+    }
+
+    /// Returns the number of out-faro shuffles which restore the cards to their current order
+    fn out_faro_restore_count(&self) -> usize {
+        out_faro_restore_count(self.cards().len())
+    }
+
+    /// Shuffle the cards into a random order. `Deck` and `Shoe` shuffle with the RNG they own rather
+    /// than `thread_rng()`.
     fn shuffle(&mut self) {
         self.knuth_shuffle(&mut thread_rng());
     }
 
-    /// Shuffle the cards into a random but predictable order
+    /// Shuffle the cards into a random but predictable order with a `Pcg32` seeded from the seed.
+    /// `Deck` and `Shoe` instead reseed the RNG they own from the seed and shuffle with it.
     fn seeded_shuffle(&mut self, seed: u64) {
         self.knuth_shuffle(&mut Pcg32::seed_from_u64(seed));
     }
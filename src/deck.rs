@@ -1,8 +1,60 @@
 use std::result::Result;
+use std::str::FromStr;
 use std::vec::Vec;
 
+use rand::{CryptoRng, Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
 use super::*;
 
+/// The default RNG of a `Deck` or `Shoe`, which draws from rand's `thread_rng()`, a
+/// cryptographically secure generator seeded from the operating system. Once it is seeded, e.g. by
+/// `seeded_shuffle()`, it draws from a `ChaCha20Rng` with the seed instead.
+#[derive(Clone, Default, Debug)]
+pub struct DefaultRng {
+    seeded: Option<Box<ChaCha20Rng>>,
+}
+
+impl RngCore for DefaultRng {
+    fn next_u32(&mut self) -> u32 {
+        match &mut self.seeded {
+            Some(rng) => rng.next_u32(),
+            None => rand::thread_rng().next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match &mut self.seeded {
+            Some(rng) => rng.next_u64(),
+            None => rand::thread_rng().next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match &mut self.seeded {
+            Some(rng) => rng.fill_bytes(dest),
+            None => rand::thread_rng().fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match &mut self.seeded {
+            Some(rng) => rng.try_fill_bytes(dest),
+            None => rand::thread_rng().try_fill_bytes(dest),
+        }
+    }
+}
+
+impl SeedableRng for DefaultRng {
+    type Seed = <ChaCha20Rng as SeedableRng>::Seed;
+
+    fn from_seed(seed: Self::Seed) -> DefaultRng {
+        DefaultRng { seeded: Some(Box::new(ChaCha20Rng::from_seed(seed))) }
+    }
+}
+
+impl CryptoRng for DefaultRng {}
+
 /// The `Deck` represents a deck of zero or more cards. A default deck is 52 playing cards.
 /// Internally the deck consists of two stacks consisting of dealt and undealt cards. The dealt stack
 /// receives cards as they are dealt from the undealt stack.
@@ -15,16 +67,50 @@ use super::*;
 ///
 /// A deck cannot have more cards added or removed to it once it is created.
 ///
+/// The deck owns the RNG which `shuffle()`, `reset_shuffle()` and `seeded_shuffle()` use. By default
+/// this is `DefaultRng` but any other seedable RNG, e.g. a ChaCha20 generator, may be given with
+/// `with_rng()`.
+///
+/// # Example
+///
+/// ```
+/// use deckofcards::{Cards, Deck};
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+/// let mut d1 = Deck::new().with_rng(ChaCha20Rng::seed_from_u64(42));
+/// let mut d2 = Deck::new().with_rng(ChaCha20Rng::seed_from_u64(42));
+/// d1.shuffle();
+/// d2.shuffle();
+/// assert_eq!(d1.cards(), d2.cards());
+/// ```
 #[derive(Clone)]
-pub struct Deck {
+pub struct Deck<R = DefaultRng> {
     /// A deck contains zero or more cards
     cards: Vec<Card>,
     /// Dealt cards are cards which have been dealt in calls but are still members of the deck
     /// they remain dealt until the deck is reshuffled or reset.
     dealt_cards: Vec<Card>,
+    /// The RNG used to shuffle the deck
+    rng: R,
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl Cards for Deck {
+impl FromStr for Deck {
+    type Err = ParseError;
+
+    /// Parses a deck of comma separated undealt cards from the bottom card to the top card, e.g.
+    /// "2S,3S,4S" where the Four of Spades is dealt first
+    fn from_str(s: &str) -> Result<Deck, ParseError> {
+        Ok(Deck::from_cards(s.parse::<Hand>()?.cards()))
+    }
+}
+
+impl<R: RngCore + SeedableRng> Cards for Deck<R> {
     fn cards(&self) -> &[Card] {
         self.cards.as_slice()
     }
@@ -32,6 +118,19 @@ impl Cards for Deck {
     fn mut_cards(&mut self) -> &mut [Card] {
         self.cards.as_mut_slice()
     }
+
+    /// Shuffle the cards into a random order with the deck's RNG
+    fn shuffle(&mut self) {
+        knuth_shuffle(&mut self.cards, &mut self.rng);
+    }
+
+    /// Reseeds the deck's RNG from the seed and shuffles with it, so the order is predictable and
+    /// later shuffles carry on from the seed. The same seed gives a different order with a
+    /// different type of RNG.
+    fn seeded_shuffle(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+        self.shuffle();
+    }
 }
 
 impl Deck {
@@ -40,14 +139,57 @@ impl Deck {
         Deck::from_cards(Card::all_cards())
     }
 
+    /// Creates a new `Deck` containing the standard set of 52 cards plus the number of Jokers. The
+    /// Jokers are taken in turn from `Card::jokers()`, so a deck with two Jokers has a black and a
+    /// red Joker. After the four distinct Jokers they start again, so a deck with more than four
+    /// Jokers holds the same Joker more than once.
+    pub fn with_jokers(jokers: usize) -> Deck {
+        let mut cards = Card::all_cards().to_vec();
+        cards.extend(Card::jokers().iter().cycle().take(jokers));
+        Deck::from_cards(&cards)
+    }
+
     /// Creates a new `Deck` containing the specified cards
     pub fn from_cards(cards: &[Card]) -> Deck {
         Deck {
             cards: cards.to_vec(),
             dealt_cards: Vec::with_capacity(cards.len()),
+            rng: DefaultRng::default(),
         }
     }
 
+    /// Creates a `Deck` with the undealt and dealt piles
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_piles(cards: Vec<Card>, dealt_cards: Vec<Card>) -> Deck {
+        Deck { cards, dealt_cards, rng: DefaultRng::default() }
+    }
+
+    /// Creates a `Deck` of the 52 standard cards in the order with the index
+    pub fn from_order_index(index: &PermutationIndex) -> Result<Deck, PermutationError> {
+        Ok(Deck::from_cards(&permutation_from_index(index)?))
+    }
+
+    /// Creates a `Deck` of the 52 standard cards in the order with the id made by `order_id()`
+    pub fn from_order_id(id: &str) -> Result<Deck, PermutationError> {
+        Deck::from_order_index(&permutation_index_from_id(id)?)
+    }
+}
+
+impl<R: RngCore + SeedableRng> Deck<R> {
+    /// Returns the deck with the RNG which is used to shuffle it from now on
+    pub fn with_rng<S: RngCore + SeedableRng>(self, rng: S) -> Deck<S> {
+        Deck {
+            cards: self.cards,
+            dealt_cards: self.dealt_cards,
+            rng,
+        }
+    }
+
+    /// Returns the RNG used to shuffle the deck
+    pub fn rng(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
     /// Returns the number of remaining undealt cards in the `Deck`
     pub fn undealt_count(&self) -> usize {
         self.cards.len()
@@ -71,29 +213,41 @@ impl Deck {
     /// Tells you the top card (very next to be drawn) in the undealt deck
     /// without dealing it.
     pub fn top_card(&self) -> Option<Card> {
-        self.cards().last().map(|card| *card)
+        self.cards().last().copied()
     }
 
     /// Tells you the bottom card (very last to be drawn) in the undealt deck
     /// without dealing it.
     pub fn bottom_card(&self) -> Option<Card> {
-        self.cards().first().map(|card| *card)
+        self.cards().first().copied()
     }
 
     /// Deals the card from the undealt pile. If there are no cards left, the function
     /// will return an error.
-    pub fn deal_one(&mut self) -> Result<Card, &'static str> {
+    pub fn deal_one(&mut self) -> Result<Card, DealError> {
         if let Some(card) = self.cards.pop() {
             self.dealt_cards.push(card);
             Ok(card)
         } else {
-            Err("No cards left")
+            Err(DealError::NoCardsLeft)
         }
     }
 
+    /// Deals a card chosen at random with the deck's RNG from anywhere in the undealt pile. If there
+    /// are no cards left, the function will return an error.
+    pub fn deal_random(&mut self) -> Result<Card, DealError> {
+        if self.cards.is_empty() {
+            return Err(DealError::NoCardsLeft);
+        }
+        let index = self.rng.gen_range(0..self.cards.len());
+        let card = self.cards.remove(index);
+        self.dealt_cards.push(card);
+        Ok(card)
+    }
+
     /// Deals one or more card from the undealt pile and returns them as an array.
     pub fn deal(&mut self, numcards: usize) -> Vec<Card> {
-        let mut result: Vec<Card> = Vec::with_capacity(numcards as usize);
+        let mut result: Vec<Card> = Vec::with_capacity(numcards);
         for _ in 0..numcards {
             if let Ok(card) = self.deal_one() {
                 result.push(card);
@@ -128,9 +282,62 @@ impl Deck {
         self.dealt_cards.clear();
     }
 
+    /// Makes the undealt and dealt cards the same as those of the other deck while keeping this
+    /// deck's RNG
+    pub(crate) fn copy_cards_from<S>(&mut self, other: &Deck<S>) {
+        self.cards.clone_from(&other.cards);
+        self.dealt_cards.clone_from(&other.dealt_cards);
+    }
+
+    /// Removes the dealt cards from the deck, leaving the dealt pile empty
+    pub(crate) fn take_dealt_cards(&mut self) -> Vec<Card> {
+        std::mem::take(&mut self.dealt_cards)
+    }
+
+    /// Adds the cards to the top of the undealt pile
+    pub(crate) fn extend_undealt_cards<I: IntoIterator<Item = Card>>(&mut self, cards: I) {
+        self.cards.extend(cards);
+    }
+
+    /// Inserts a card into the undealt pile at a position chosen by the deck's RNG
+    pub(crate) fn insert_undealt_card_randomly(&mut self, card: Card) {
+        let index = self.rng.gen_range(0..=self.cards.len());
+        self.cards.insert(index, card);
+    }
+
     /// Resets and shuffles the deck
     pub fn reset_shuffle(&mut self) {
         self.reset();
         self.shuffle();
     }
+
+    /// Returns every card, dealt and undealt, in the order the deck would have after a `reset()`
+    pub(crate) fn order(&self) -> Vec<Card> {
+        let mut order = self.cards.clone();
+        order.extend(self.dealt_cards.iter().rev());
+        order
+    }
+
+    /// Returns the index from 0 to 52! - 1 of the order of the deck, including the dealt cards, see
+    /// `permutation_index`. The deck must hold the 52 standard cards.
+    pub fn order_index(&self) -> Result<PermutationIndex, PermutationError> {
+        permutation_index(&self.order())
+    }
+
+    /// Returns the order of the deck, including the dealt cards, as a 38 character id which can be
+    /// passed to `Deck::from_order_id()` to make a deck in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use deckofcards::{Cards, Deck};
+    /// let mut deck = Deck::new();
+    /// deck.shuffle();
+    /// let id = deck.order_id().unwrap();
+    /// assert_eq!(id.len(), 38);
+    /// assert_eq!(Deck::from_order_id(&id).unwrap().cards(), deck.cards());
+    /// ```
+    pub fn order_id(&self) -> Result<String, PermutationError> {
+        Ok(permutation_index_to_id(&self.order_index()?))
+    }
 }
@@ -0,0 +1,161 @@
+use rand::{RngCore, SeedableRng};
+use rand_pcg::Pcg32;
+
+use super::*;
+
+/// The results of one player's hand over every board that was evaluated. A tie is any board where
+/// the player shares the pot with one or more other players.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Equity {
+    /// The number of boards where the player won outright
+    pub wins: u64,
+    /// The number of boards where the player shared the pot
+    pub ties: u64,
+    /// The number of boards where the player lost
+    pub losses: u64,
+    /// The total share of the pot won over every board, e.g. a two way tie adds 0.5
+    pub share: f64,
+}
+
+impl Equity {
+    /// Returns the number of boards evaluated
+    pub fn trials(&self) -> u64 {
+        self.wins + self.ties + self.losses
+    }
+
+    /// Returns the percentage of boards won outright
+    pub fn win_percent(&self) -> f64 {
+        self.percent(self.wins as f64)
+    }
+
+    /// Returns the percentage of boards tied
+    pub fn tie_percent(&self) -> f64 {
+        self.percent(self.ties as f64)
+    }
+
+    /// Returns the percentage of boards lost
+    pub fn loss_percent(&self) -> f64 {
+        self.percent(self.losses as f64)
+    }
+
+    /// Returns the percentage of the pot the player can expect to win
+    pub fn equity_percent(&self) -> f64 {
+        self.percent(self.share)
+    }
+
+    fn percent(&self, value: f64) -> f64 {
+        if self.trials() == 0 {
+            0.0
+        } else {
+            value * 100.0 / self.trials() as f64
+        }
+    }
+}
+
+/// Holds the known cards of an equity calculation and scores boards against them
+struct Showdown {
+    players: Vec<u64>,
+    board: u64,
+    remaining: Vec<Card>,
+    missing: usize,
+}
+
+impl Showdown {
+    fn new(players: &[Hand], board: &[Card], dead: &[Card]) -> Result<Showdown, EquityError> {
+        if players.len() < 2 {
+            return Err(EquityError::TooFewPlayers { found: players.len() });
+        }
+        if board.len() > 5 {
+            return Err(EquityError::TooManyBoardCards { found: board.len() });
+        }
+        if let Some((player, hand)) = players.iter().enumerate().find(|(_, p)| p.len() != 2) {
+            return Err(EquityError::WrongHoleSize { player, found: hand.len() });
+        }
+        if players.iter().any(|p| p.cards().iter().any(|c| c.is_joker())) || board.iter().any(|c| c.is_joker()) {
+            return Err(EquityError::Joker);
+        }
+        let mut known: Vec<Card> = players.iter().flat_map(|p| p.cards().iter().cloned()).collect();
+        known.extend(board);
+        known.extend(dead);
+        let known = CardSet::from_cards(&known);
+        if known.len() != players.iter().map(|p| p.len()).sum::<usize>() + board.len() + dead.len() {
+            return Err(EquityError::DuplicateCard);
+        }
+        let remaining: Vec<Card> = known.complement().iter().collect();
+        let missing = 5 - board.len();
+        if remaining.len() < missing {
+            return Err(EquityError::NotEnoughCards { needed: missing, available: remaining.len() });
+        }
+        Ok(Showdown {
+            players: players.iter().map(|p| card_mask(p.cards())).collect(),
+            board: card_mask(board),
+            remaining,
+            missing,
+        })
+    }
+
+    /// Scores a completed board for every player
+    fn score(&self, runout: &[Card], equities: &mut [Equity]) {
+        let board = self.board | card_mask(runout);
+        let values: Vec<HandValue> = self.players.iter().map(|p| evaluate_mask(p | board)).collect();
+        let best = *values.iter().max().unwrap();
+        let winners = values.iter().filter(|v| **v == best).count();
+        for (equity, value) in equities.iter_mut().zip(values) {
+            if value < best {
+                equity.losses += 1;
+            } else if winners == 1 {
+                equity.wins += 1;
+                equity.share += 1.0;
+            } else {
+                equity.ties += 1;
+                equity.share += 1.0 / winners as f64;
+            }
+        }
+    }
+}
+
+/// Calculates the equity of each player's hole cards in hold'em by evaluating every possible way
+/// the board can be completed from the remaining cards. The board may be empty or hold up to five
+/// cards and any dead cards are removed from the deck.
+///
+/// Evaluating every board from the start of a hand is over a million boards, so consider
+/// `monte_carlo_equity` when the board is empty.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, exhaustive_equity};
+/// let players = [hand!("AS", "AD"), hand!("KS", "KD")];
+/// let board = hand!("2C", "7H", "9D", "JC");
+/// let equity = exhaustive_equity(&players, board.cards(), &[]).unwrap();
+/// assert_eq!(equity[1].wins, 2);
+/// # }
+/// ```
+pub fn exhaustive_equity(players: &[Hand], board: &[Card], dead: &[Card]) -> Result<Vec<Equity>, EquityError> {
+    let showdown = Showdown::new(players, board, dead)?;
+    let mut equities = vec![Equity::default(); players.len()];
+    poker::for_each_combination(&showdown.remaining, showdown.missing, |runout| {
+        showdown.score(runout, &mut equities);
+    });
+    Ok(equities)
+}
+
+/// Estimates the equity of each player's hole cards in hold'em by dealing the rest of the board at
+/// random from the remaining cards for the number of trials. Each trial is a `seeded_shuffle()`
+/// of the remaining cards with its own seed, drawn from a `Pcg32` seeded from the seed, so the same
+/// seed always deals the same boards and results are reproducible.
+pub fn monte_carlo_equity(players: &[Hand], board: &[Card], dead: &[Card], trials: usize, seed: u64) -> Result<Vec<Equity>, EquityError> {
+    let showdown = Showdown::new(players, board, dead)?;
+    let mut equities = vec![Equity::default(); players.len()];
+    let mut deck = Deck::from_cards(&showdown.remaining);
+    let mut seeds = Pcg32::seed_from_u64(seed);
+    for _ in 0..trials {
+        deck.reset();
+        deck.seeded_shuffle(seeds.next_u64());
+        let runout = deck.deal(showdown.missing);
+        showdown.score(&runout, &mut equities);
+    }
+    Ok(equities)
+}
@@ -0,0 +1,405 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error from parsing a `Card`, `Rank` or `Suit` from text. Each error carries the whole input
+/// and the position in chars where the problem was found, even when the problem is with one card
+/// of a `Hand`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// The card starting at the position is the wrong number of chars
+    WrongLength {
+        /// The text being parsed
+        input: String,
+        /// The position in chars of the start of the card
+        position: usize,
+        /// The number of chars expected from the position
+        expected: usize,
+        /// The number of chars found from the position
+        found: usize,
+    },
+    /// The char at the position is not a rank
+    InvalidRank {
+        /// The text being parsed
+        input: String,
+        /// The position of the char in chars
+        position: usize,
+        /// The char found
+        found: char,
+    },
+    /// The char at the position is not a suit
+    InvalidSuit {
+        /// The text being parsed
+        input: String,
+        /// The position of the char in chars
+        position: usize,
+        /// The char found
+        found: char,
+    },
+    /// The text starting at the position is not the name of a card, e.g. "Ace of Spades"
+    InvalidName {
+        /// The text being parsed
+        input: String,
+        /// The position in chars of the start of the name
+        position: usize,
+    },
+}
+
+impl ParseError {
+    /// Returns the text which could not be parsed
+    pub fn input(&self) -> &str {
+        match self {
+            ParseError::WrongLength { input, .. } |
+            ParseError::InvalidRank { input, .. } |
+            ParseError::InvalidSuit { input, .. } |
+            ParseError::InvalidName { input, .. } => input,
+        }
+    }
+
+    /// Returns the position in chars of the problem within the input
+    pub fn position(&self) -> usize {
+        match self {
+            ParseError::WrongLength { position, .. } |
+            ParseError::InvalidRank { position, .. } |
+            ParseError::InvalidSuit { position, .. } |
+            ParseError::InvalidName { position, .. } => *position,
+        }
+    }
+
+    /// Makes the error relative to a larger input, where the text which failed to parse starts at
+    /// the offset in chars
+    pub(crate) fn within(self, input: &str, offset: usize) -> ParseError {
+        let input = input.to_string();
+        match self {
+            ParseError::WrongLength { position, expected, found, .. } => ParseError::WrongLength { input, position: position + offset, expected, found },
+            ParseError::InvalidRank { position, found, .. } => ParseError::InvalidRank { input, position: position + offset, found },
+            ParseError::InvalidSuit { position, found, .. } => ParseError::InvalidSuit { input, position: position + offset, found },
+            ParseError::InvalidName { position, .. } => ParseError::InvalidName { input, position: position + offset },
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::WrongLength { input, position, expected, found } => write!(f, "Expected {} chars but found {} at position {} in {:?}", expected, found, position, input),
+            ParseError::InvalidRank { input, position, found } => write!(f, "Invalid rank {:?} at position {} in {:?}", found, position, input),
+            ParseError::InvalidSuit { input, position, found } => write!(f, "Invalid suit {:?} at position {} in {:?}", found, position, input),
+            ParseError::InvalidName { input, position } => write!(f, "Invalid card name at position {} in {:?}", position, input),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// An error from converting an ordinal which is out of range into a `Rank` or `Suit`
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct InvalidOrdinal {
+    /// The ordinal which is out of range
+    pub ordinal: usize,
+}
+
+impl fmt::Display for InvalidOrdinal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid ordinal {}", self.ordinal)
+    }
+}
+
+impl Error for InvalidOrdinal {}
+
+/// An error from dealing a card
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DealError {
+    /// There are no undealt cards left
+    NoCardsLeft,
+}
+
+impl fmt::Display for DealError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DealError::NoCardsLeft => write!(f, "No cards left"),
+        }
+    }
+}
+
+impl Error for DealError {}
+
+/// An error from evaluating a poker hand
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EvalError {
+    /// The hand has the wrong number of cards for the evaluator
+    WrongHandSize {
+        /// The fewest cards allowed
+        min: usize,
+        /// The most cards allowed
+        max: usize,
+        /// The number of cards found
+        found: usize,
+    },
+    /// An Omaha hand has the wrong number of hole cards
+    WrongHoleSize {
+        /// The fewest hole cards allowed
+        min: usize,
+        /// The most hole cards allowed
+        max: usize,
+        /// The number of hole cards found
+        found: usize,
+    },
+    /// An Omaha board has the wrong number of cards
+    WrongBoardSize {
+        /// The fewest board cards allowed
+        min: usize,
+        /// The most board cards allowed
+        max: usize,
+        /// The number of board cards found
+        found: usize,
+    },
+    /// A Joker which does not stand for another card cannot be evaluated
+    Joker,
+    /// The hand holds the same card more than once
+    DuplicateCard,
+}
+
+/// Writes how many of something were expected, e.g. "exactly 5" or "between 5 and 7"
+fn write_count(f: &mut fmt::Formatter, what: &str, min: usize, max: usize, found: usize) -> fmt::Result {
+    if min == max {
+        write!(f, "{} must contain exactly {} cards but found {}", what, min, found)
+    } else {
+        write!(f, "{} must contain between {} and {} cards but found {}", what, min, max, found)
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalError::WrongHandSize { min, max, found } => write_count(f, "Hand", *min, *max, *found),
+            EvalError::WrongHoleSize { min, max, found } => write_count(f, "Hole cards", *min, *max, *found),
+            EvalError::WrongBoardSize { min, max, found } => write_count(f, "Board", *min, *max, *found),
+            EvalError::Joker => write!(f, "Hand cannot be evaluated with Jokers"),
+            EvalError::DuplicateCard => write!(f, "Hand contains the same card more than once"),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+/// An error from calculating equity
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EquityError {
+    /// Fewer than two players were given
+    TooFewPlayers {
+        /// The number of players found
+        found: usize,
+    },
+    /// The board has more than five cards
+    TooManyBoardCards {
+        /// The number of board cards found
+        found: usize,
+    },
+    /// A player does not hold exactly two cards
+    WrongHoleSize {
+        /// The index of the player
+        player: usize,
+        /// The number of cards the player holds
+        found: usize,
+    },
+    /// A Joker which does not stand for another card cannot be evaluated
+    Joker,
+    /// The same card is held by more than one player, the board or the dead cards
+    DuplicateCard,
+    /// Too few cards remain to complete the board
+    NotEnoughCards {
+        /// The number of cards needed to complete the board
+        needed: usize,
+        /// The number of cards which remain
+        available: usize,
+    },
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EquityError::TooFewPlayers { found } => write!(f, "At least two players are required but found {}", found),
+            EquityError::TooManyBoardCards { found } => write!(f, "Board cannot contain more than five cards but found {}", found),
+            EquityError::WrongHoleSize { player, found } => write!(f, "Player {} must hold two cards but holds {}", player, found),
+            EquityError::Joker => write!(f, "Equity cannot be calculated with Jokers"),
+            EquityError::DuplicateCard => write!(f, "The same card cannot be used more than once"),
+            EquityError::NotEnoughCards { needed, available } => write!(f, "{} cards are needed to complete the board but only {} remain", needed, available),
+        }
+    }
+}
+
+impl Error for EquityError {}
+
+/// An error from parsing a `Range`. Each error carries the part of the range which failed, e.g.
+/// "AKx" from "QQ+, AKx".
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RangeError {
+    /// The item is not a starting hand, e.g. it has the wrong number of chars
+    InvalidHand {
+        /// The part of the range which failed
+        item: String,
+    },
+    /// The item has a char which is not a rank, or is a Joker
+    InvalidRank {
+        /// The part of the range which failed
+        item: String,
+        /// The char found
+        found: char,
+    },
+    /// The item ends with something other than 's' for suited or 'o' for offsuit
+    InvalidSuitedness {
+        /// The part of the range which failed
+        item: String,
+        /// The char found
+        found: char,
+    },
+    /// The item is a pair marked as suited or offsuit
+    SuitedPair {
+        /// The part of the range which failed
+        item: String,
+    },
+    /// The ends of a span are not the same kind of hand, or do not share a high card or a gap
+    InvalidSpan {
+        /// The part of the range which failed
+        item: String,
+    },
+    /// The weight is not a number between 0 and 1
+    InvalidWeight {
+        /// The part of the range which failed
+        item: String,
+    },
+    /// The item is not two different cards, e.g. "AsKd"
+    InvalidCards {
+        /// The part of the range which failed
+        item: String,
+    },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeError::InvalidHand { item } => write!(f, "Invalid hand {:?} in range", item),
+            RangeError::InvalidRank { item, found } => write!(f, "Invalid rank {:?} in {:?} in range", found, item),
+            RangeError::InvalidSuitedness { item, found } => write!(f, "Invalid suitedness {:?} in {:?} in range", found, item),
+            RangeError::SuitedPair { item } => write!(f, "Pair {:?} cannot be suited or offsuit", item),
+            RangeError::InvalidSpan { item } => write!(f, "Range ends of {:?} must be the same kind of hand and share a high card or a gap", item),
+            RangeError::InvalidWeight { item } => write!(f, "Weight of {:?} must be between 0 and 1", item),
+            RangeError::InvalidCards { item } => write!(f, "Invalid cards {:?} in range", item),
+        }
+    }
+}
+
+impl Error for RangeError {}
+
+/// An error from analyzing a shuffle with `analyze_shuffle`
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AnalysisError {
+    /// The number of trials is zero
+    NoTrials,
+    /// The deck has fewer than two undealt cards
+    TooFewCards {
+        /// The number of undealt cards found
+        found: usize,
+    },
+    /// The deck holds the same card more than once
+    DuplicateCard,
+    /// The shuffle added, removed or replaced cards
+    CardsChanged,
+}
+
+impl fmt::Display for AnalysisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnalysisError::NoTrials => write!(f, "Trials must be more than zero"),
+            AnalysisError::TooFewCards { found } => write!(f, "Deck must contain at least two cards but found {}", found),
+            AnalysisError::DuplicateCard => write!(f, "Deck must not contain the same card twice"),
+            AnalysisError::CardsChanged => write!(f, "Shuffle must keep the same cards in the deck"),
+        }
+    }
+}
+
+impl Error for AnalysisError {}
+
+/// An error from ranking or unranking the order of a deck
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PermutationError {
+    /// The cards are not each of the 52 standard cards exactly once
+    NotStandardCards,
+    /// The index is not below 52!
+    IndexOutOfRange,
+    /// The id is not 38 characters long
+    WrongIdLength {
+        /// The length of the id found
+        found: usize,
+    },
+    /// The id has a char which is not a base-62 digit, 0-9, A-Z or a-z
+    InvalidIdChar {
+        /// The char found
+        found: char,
+    },
+}
+
+impl fmt::Display for PermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PermutationError::NotStandardCards => write!(f, "Order must contain each of the 52 standard cards once"),
+            PermutationError::IndexOutOfRange => write!(f, "Index must be less than 52!"),
+            PermutationError::WrongIdLength { found } => write!(f, "Id must be 38 characters but found {}", found),
+            PermutationError::InvalidIdChar { found } => write!(f, "Id must only contain 0-9, A-Z and a-z but found {:?}", found),
+        }
+    }
+}
+
+impl Error for PermutationError {}
+
+/// An error from verifying a provably fair deal
+#[cfg(feature = "fair")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FairError {
+    /// The server seed is not 64 hex digits
+    InvalidSeed,
+    /// The server seed does not hash to the commitment
+    CommitmentMismatch,
+    /// The deck is not in the order made by the seeds and nonce
+    OrderMismatch,
+}
+
+#[cfg(feature = "fair")]
+impl fmt::Display for FairError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FairError::InvalidSeed => write!(f, "Server seed must be 64 hex digits"),
+            FairError::CommitmentMismatch => write!(f, "Server seed does not match the commitment"),
+            FairError::OrderMismatch => write!(f, "Deck is not in the order made by the seeds"),
+        }
+    }
+}
+
+#[cfg(feature = "fair")]
+impl Error for FairError {}
+
+/// An error from revealing a card of an `EncryptedDeck`
+#[cfg(feature = "mental-poker")]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RevealError {
+    /// There is no card at the index
+    NoCard {
+        /// The index asked for
+        index: usize,
+    },
+    /// The keys do not decrypt the card, e.g. a party's key is missing
+    CannotDecrypt,
+}
+
+#[cfg(feature = "mental-poker")]
+impl fmt::Display for RevealError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RevealError::NoCard { index } => write!(f, "No card at index {}", index),
+            RevealError::CannotDecrypt => write!(f, "Keys do not decrypt the card"),
+        }
+    }
+}
+
+#[cfg(feature = "mental-poker")]
+impl Error for RevealError {}
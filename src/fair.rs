@@ -0,0 +1,187 @@
+use std::fmt::Write;
+
+use rand::rngs::OsRng;
+use rand::{RngCore, SeedableRng};
+use sha2::{Digest, Sha256};
+
+use super::*;
+
+/// Formats the bytes as lowercase hex
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut s, b| {
+        let _ = write!(s, "{:02x}", b);
+        s
+    })
+}
+
+/// The secret seed chosen by the server for a provably fair deal. Before the deal the server
+/// publishes the `commitment()`, a SHA-256 hash of the seed, and after the deal it reveals the seed
+/// so the player can check it matches the commitment and reproduce the shuffle.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ServerSeed {
+    seed: [u8; 32],
+}
+
+impl ServerSeed {
+    /// Creates a server seed from the operating system's secure random number generator
+    pub fn new() -> ServerSeed {
+        let mut seed = [0; 32];
+        OsRng.fill_bytes(&mut seed);
+        ServerSeed { seed }
+    }
+
+    /// Creates a server seed from its bytes
+    pub fn from_bytes(seed: [u8; 32]) -> ServerSeed {
+        ServerSeed { seed }
+    }
+
+    /// Creates a server seed from a revealed seed of 64 hex digits
+    pub fn from_hex(s: &str) -> Result<ServerSeed, FairError> {
+        if s.len() != 64 || !s.is_ascii() {
+            return Err(FairError::InvalidSeed);
+        }
+        let mut seed = [0; 32];
+        for (i, byte) in seed.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).map_err(|_| FairError::InvalidSeed)?;
+        }
+        Ok(ServerSeed { seed })
+    }
+
+    /// Returns the bytes of the seed
+    pub fn bytes(&self) -> &[u8; 32] {
+        &self.seed
+    }
+
+    /// Returns the seed as 64 lowercase hex digits, which is revealed after the deal
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.seed)
+    }
+
+    /// Returns the commitment to the seed, the SHA-256 hash of its bytes as 64 lowercase hex
+    /// digits, which is published before the deal
+    pub fn commitment(&self) -> String {
+        to_hex(&Sha256::digest(self.seed))
+    }
+}
+
+impl Default for ServerSeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A random number generator for a provably fair deal which combines the server seed, client seed
+/// and nonce through SHA-256. Anyone with the seeds can reproduce its output.
+///
+/// The output is a sequence of blocks, each the SHA-256 hash of the 32 bytes of the server seed,
+/// the UTF-8 bytes of the client seed, then the nonce and a block counter starting at 0, both as
+/// 8 byte big-endian integers. Each block gives eight 32-bit big-endian numbers in turn.
+#[derive(Clone, Debug)]
+pub struct FairRng {
+    prefix: Vec<u8>,
+    counter: u64,
+    block: [u8; 32],
+    used: usize,
+}
+
+impl FairRng {
+    /// Creates the generator for the seeds and nonce. The nonce lets one pair of seeds make many
+    /// deals, e.g. one per hand played.
+    pub fn new(server_seed: &ServerSeed, client_seed: &str, nonce: u64) -> FairRng {
+        let mut prefix = server_seed.bytes().to_vec();
+        prefix.extend_from_slice(client_seed.as_bytes());
+        prefix.extend_from_slice(&nonce.to_be_bytes());
+        FairRng { prefix, counter: 0, block: [0; 32], used: 32 }
+    }
+}
+
+impl RngCore for FairRng {
+    fn next_u32(&mut self) -> u32 {
+        if self.used == self.block.len() {
+            let mut hasher = Sha256::new();
+            hasher.update(&self.prefix);
+            hasher.update(self.counter.to_be_bytes());
+            self.block.copy_from_slice(&hasher.finalize());
+            self.counter += 1;
+            self.used = 0;
+        }
+        let value = u32::from_be_bytes(self.block[self.used..self.used + 4].try_into().unwrap());
+        self.used += 4;
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.chunks_mut(4).for_each(|chunk| {
+            let bytes = self.next_u32().to_be_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        });
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Returns a number from 0 to n - 1 without bias. Numbers at or above the largest multiple of n
+/// which fits in 32 bits are rejected and drawn again, then the remainder after dividing by n is
+/// taken.
+fn fair_below(rng: &mut FairRng, n: u32) -> u32 {
+    let zone = u32::MAX - (u32::MAX - n + 1) % n;
+    loop {
+        let value = rng.next_u32();
+        if value <= zone {
+            return value % n;
+        }
+    }
+}
+
+/// Shuffles the cards for a provably fair deal. This is a Knuth shuffle which, for each index i
+/// from the end of the slice down to 1, swaps the card at i with the card at an index from 0 to i
+/// drawn by rejection sampling from the generator. Unlike `knuth_shuffle` the algorithm is
+/// documented here and will not change, so a deal can always be verified.
+pub fn fair_shuffle(cards: &mut [Card], rng: &mut FairRng) {
+    for i in (1..cards.len()).rev() {
+        let j = fair_below(rng, i as u32 + 1) as usize;
+        cards.swap(i, j);
+    }
+}
+
+/// Creates a provably fair shuffled `Deck` of the standard 52 cards from the seeds and nonce. The
+/// deck starts in the order of `Deck::new()` and is shuffled by `fair_shuffle`.
+///
+/// # Example
+///
+/// ```
+/// use deckofcards::{ServerSeed, fair_deck, verify_fair_deck};
+/// // The server publishes the commitment before the player picks the client seed
+/// let server_seed = ServerSeed::new();
+/// let commitment = server_seed.commitment();
+/// let mut deck = fair_deck(&server_seed, "player chosen seed", 1);
+/// deck.deal(5);
+/// // Afterwards the server reveals its seed and the player checks the deal
+/// let revealed = ServerSeed::from_hex(&server_seed.to_hex()).unwrap();
+/// assert!(verify_fair_deck(&commitment, &revealed, "player chosen seed", 1, &deck).is_ok());
+/// ```
+pub fn fair_deck(server_seed: &ServerSeed, client_seed: &str, nonce: u64) -> Deck {
+    let mut deck = Deck::new();
+    fair_shuffle(deck.mut_cards(), &mut FairRng::new(server_seed, client_seed, nonce));
+    deck
+}
+
+/// Verifies a provably fair deal, i.e. that the revealed server seed matches the commitment and
+/// that the deck, including any dealt cards, is in the order made by `fair_deck` from the seeds
+/// and nonce.
+pub fn verify_fair_deck<R: RngCore + SeedableRng>(commitment: &str, server_seed: &ServerSeed, client_seed: &str, nonce: u64, deck: &Deck<R>) -> Result<(), FairError> {
+    if !server_seed.commitment().eq_ignore_ascii_case(commitment.trim()) {
+        return Err(FairError::CommitmentMismatch);
+    }
+    if fair_deck(server_seed, client_seed, nonce).order() != deck.order() {
+        return Err(FairError::OrderMismatch);
+    }
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use super::*;
+
+/// The first code point of the Unicode Playing Cards block, which is the card back
+const BLOCK_START: u32 = 0x1F0A0;
+
+/// The offset within a suit's row of the Knight, which sits between the Jack and Queen
+const KNIGHT: u32 = 0xC;
+
+/// The offset within a row of the Joker, the red Joker on the Hearts row, the black Joker on the
+/// Diamonds row and the white Joker on the Clubs row
+const JOKER: u32 = 0xF;
+
+/// A glyph from the Unicode Playing Cards block, which has a glyph for every `Card`, a Knight of
+/// each suit as used in tarot decks, the back of a card and a white Joker.
+///
+/// Each suit has a row of 16 code points starting at U+1F0A0 for Spades, U+1F0B0 for Hearts,
+/// U+1F0C0 for Diamonds and U+1F0D0 for Clubs, with the Ace at 1 through to the King at E. The red
+/// Joker is U+1F0BF, the black Joker U+1F0CF and the white Joker U+1F0DF.
+///
+/// # Example
+///
+/// ```
+/// use deckofcards::{Card, CardGlyph, Rank, Suit};
+/// let ace = Card::new(Rank::Ace, Suit::Spades);
+/// assert_eq!(ace.to_unicode(), '🂡');
+/// assert_eq!(Card::from_unicode('🂡'), Some(ace));
+/// assert_eq!(CardGlyph::from_char('🂬'), Some(CardGlyph::Knight(Suit::Spades)));
+/// assert_eq!(CardGlyph::Back.to_char(), '🂠');
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CardGlyph {
+    /// A playing card or Joker
+    Card(Card),
+    /// The Knight of a suit
+    Knight(Suit),
+    /// The back of a card
+    Back,
+    /// The white Joker, which has no `Card`
+    WhiteJoker,
+}
+
+impl fmt::Display for CardGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl CardGlyph {
+    /// Returns the code point of the glyph. A Joker's glyph goes by its colour, so the Jokers of
+    /// Hearts and Diamonds are the red Joker and the Jokers of Spades and Clubs are the black Joker.
+    pub fn to_char(&self) -> char {
+        let code = match *self {
+            CardGlyph::Card(card) if card.is_joker() => {
+                let row = if card.suit.is_red() { Suit::Hearts } else { Suit::Diamonds };
+                BLOCK_START + 16 * row.ordinal() as u32 + JOKER
+            }
+            CardGlyph::Card(card) => {
+                // The Ace is first and the Knight sits between the Jack and Queen
+                let offset = match card.rank {
+                    Rank::Ace => 1,
+                    r if r > Rank::Jack => r.ordinal() as u32 + 3,
+                    r => r.ordinal() as u32 + 2,
+                };
+                BLOCK_START + 16 * card.suit.ordinal() as u32 + offset
+            }
+            CardGlyph::Knight(suit) => BLOCK_START + 16 * suit.ordinal() as u32 + KNIGHT,
+            CardGlyph::Back => BLOCK_START,
+            CardGlyph::WhiteJoker => BLOCK_START + 16 * Suit::Clubs.ordinal() as u32 + JOKER,
+        };
+        char::from_u32(code).unwrap()
+    }
+
+    /// Returns the glyph of the code point, or `None` if it is not a glyph of a card. The red Joker
+    /// is the Joker of Hearts and the black Joker is the Joker of Spades.
+    pub fn from_char(ch: char) -> Option<CardGlyph> {
+        let code = (ch as u32).checked_sub(BLOCK_START)?;
+        let (row, offset) = (code / 16, code % 16);
+        let suit = *Suit::suits().get(row as usize)?;
+        match offset {
+            0 if row == 0 => Some(CardGlyph::Back),
+            0 => None,
+            1 => Some(CardGlyph::Card(Card::new(Rank::Ace, suit))),
+            KNIGHT => Some(CardGlyph::Knight(suit)),
+            JOKER => match suit {
+                Suit::Spades => None,
+                Suit::Hearts => Some(CardGlyph::Card(Card::red_joker())),
+                Suit::Diamonds => Some(CardGlyph::Card(Card::black_joker())),
+                Suit::Clubs => Some(CardGlyph::WhiteJoker),
+            },
+            o if o < KNIGHT => Some(CardGlyph::Card(Card::new(Rank::ranks()[o as usize - 2], suit))),
+            o => Some(CardGlyph::Card(Card::new(Rank::ranks()[o as usize - 3], suit))),
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use std::fmt::{Display, Formatter, Result};
 use std::ops::AddAssign;
+use std::str::FromStr;
+
+use rand::Rng;
 
 use super::*;
 
@@ -7,7 +10,7 @@ use super::*;
 /// e.g. the cards a person is holding. A hand may be shuffled or sorted
 /// and there are functions for adding or removing cards. Unlike a `Deck`,
 /// there is no concept of dealt or undealt cards.
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct Hand {
     pub cards: Vec<Card>,
 }
@@ -25,13 +28,18 @@ impl Display for Hand {
     }
 }
 
-impl Default for Hand {
-    fn default() -> Self {
-        Self { cards: Vec::new() }
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    /// Parses a hand of cards, e.g. "AS,KD" as written by `Display`. The cards may be separated
+    /// by commas, whitespace or nothing, e.g. "As Kd" or "AsKd", and each card may be in any form
+    /// `Card::from_str()` accepts. Cards written by name must be separated by commas.
+    fn from_str(s: &str) -> std::result::Result<Hand, ParseError> {
+        Ok(Hand { cards: parse::parse_cards(s)? })
     }
 }
 
-impl<'a> AddAssign<&'a Hand> for Hand {
+impl AddAssign<&Hand> for Hand {
     fn add_assign(&mut self, rhs: &Hand) {
         self.push_hand(rhs);
     }
@@ -88,11 +96,22 @@ impl Hand {
         self.cards.extend(other.cards());
     }
 
+    /// Returns the cards as a row of glyphs from the Unicode Playing Cards block separated by spaces,
+    /// e.g. "🂡 🃎"
+    pub fn to_unicode_string(&self) -> String {
+        self.cards.iter().map(|c| c.to_unicode().to_string()).collect::<Vec<String>>().join(" ")
+    }
+
     /// Returns the number of cards
     pub fn len(&self) -> usize {
         self.cards.len()
     }
 
+    /// Tests if the `Hand` is empty
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
     /// Clears the `Hand` (makes it empty)
     pub fn clear(&mut self) {
     	self.cards.clear();
@@ -103,6 +122,16 @@ impl Hand {
         self.cards.remove(index)
     }
 
+    /// Removes a `Card` chosen at random with the given RNG and returns it, or `None` if the `Hand`
+    /// is empty
+    pub fn remove_random<T: Rng>(&mut self, rng: &mut T) -> Option<Card> {
+        if self.cards.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..self.cards.len());
+        Some(self.cards.remove(index))
+    }
+
     /// Removes the first instance of every matching card from the `Hand`
     pub fn remove_cards(&mut self, cards: &[Card]) {
         for c in cards {
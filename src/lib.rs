@@ -15,7 +15,7 @@
 macro_rules! card {
     ($s:expr) => {
         {
-            let cr = $crate::Card::from_str($s);
+            let cr = <$crate::Card as ::std::str::FromStr>::from_str($s);
             cr.unwrap_or_else(|_| {
                 panic!("Not a known card {}", $s);
             })
@@ -88,6 +88,15 @@ macro_rules! deck {
     }
 }
 
+mod error;
+pub use error::{AnalysisError, DealError, EquityError, EvalError, InvalidOrdinal, ParseError, PermutationError, RangeError};
+#[cfg(feature = "fair")]
+pub use error::FairError;
+#[cfg(feature = "mental-poker")]
+pub use error::RevealError;
+
+mod parse;
+
 mod suit;
 pub use suit::{Suit};
 
@@ -98,13 +107,67 @@ mod card;
 pub use card::{Card};
 
 mod cards;
-pub use cards::{Cards, cards_of_suit, cards_of_rank};
+pub use cards::{Cards, cards_of_suit, cards_of_rank, cut, in_faro, knuth_shuffle, out_faro, out_faro_restore_count, overhand_shuffle, riffle_shuffle, strip_cut_shuffle};
 
 mod deck;
-pub use deck::{Deck};
+pub use deck::{Deck, DefaultRng};
 
 mod hand;
 pub use hand::{Hand};
 
+mod shoe;
+pub use shoe::{ReshufflePolicy, Shoe};
+
+mod glyph;
+pub use glyph::CardGlyph;
+
+mod render;
+pub use render::{ColorScheme, Renderer};
+
+mod card_set;
+pub use card_set::{CardSet, CardSetIter};
+
+mod poker;
+pub use poker::{BestHand, HandCategory, HandRank, evaluate_best, evaluate_five, evaluate_hand};
+
+mod lookup;
+pub use lookup::{HandValue, card_mask, equivalence_class, evaluate_fast, evaluate_mask};
+
+mod lowball;
+pub use lowball::{LowHand, LowRank, evaluate_ace_to_five, evaluate_badugi, evaluate_deuce_to_seven, evaluate_eight_or_better};
+
+mod omaha;
+pub use omaha::{evaluate_omaha, evaluate_omaha_low};
+
+mod equity;
+pub use equity::{Equity, exhaustive_equity, monte_carlo_equity};
+
+mod range;
+pub use range::{Combo, Range};
+
+mod wild;
+pub use wild::{WildCards, evaluate_wild};
+
+mod analysis;
+pub use analysis::{ShuffleReport, analyze_shuffle, rising_sequences};
+
+mod permutation;
+pub use permutation::{PERMUTATION_INDEX_BYTES, PermutationIndex, permutation_from_index, permutation_index, permutation_index_from_id, permutation_index_to_id};
+
+#[cfg(feature = "fair")]
+mod fair;
+#[cfg(feature = "fair")]
+pub use fair::{FairRng, ServerSeed, fair_deck, fair_shuffle, verify_fair_deck};
+
+#[cfg(feature = "mental-poker")]
+mod mental_poker;
+#[cfg(feature = "mental-poker")]
+pub use mental_poker::{EncryptedDeck, SraKey, SraParty, sra_prime};
+#[cfg(feature = "mental-poker")]
+pub use num_bigint::BigUint;
+
+#[cfg(feature = "serde")]
+mod serialization;
+
 #[cfg(test)]
 mod tests;
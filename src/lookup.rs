@@ -0,0 +1,280 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use super::*;
+
+const RANK_BITS: u32 = 13;
+const RANK_MASK: u64 = (1 << RANK_BITS) - 1;
+const CATEGORY_SHIFT: u32 = 2 * RANK_BITS;
+
+/// A compact value for a poker hand produced by the lookup table evaluator. Values compare in the
+/// same way as the equivalent `HandRank`, i.e. a better hand always compares greater, but are far
+/// cheaper to produce, store and compare.
+///
+/// Internally the value holds the category followed by two 13-bit masks of ranks, the first of
+/// the primary ranks (e.g. the pairs) and the second of the kickers.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct HandValue(u32);
+
+impl fmt::Display for HandValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl HandValue {
+    pub(crate) fn new(category: HandCategory, primary: u64, kickers: u64) -> HandValue {
+        HandValue(((category as u32) << CATEGORY_SHIFT) | ((primary as u32) << RANK_BITS) | kickers as u32)
+    }
+
+    /// Returns the raw value
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the category of the hand
+    pub fn category(&self) -> HandCategory {
+        match self.0 >> CATEGORY_SHIFT {
+            0 => HandCategory::HighCard,
+            1 => HandCategory::OnePair,
+            2 => HandCategory::TwoPair,
+            3 => HandCategory::ThreeOfAKind,
+            4 => HandCategory::Straight,
+            5 => HandCategory::Flush,
+            6 => HandCategory::FullHouse,
+            7 => HandCategory::FourOfAKind,
+            8 => HandCategory::StraightFlush,
+            _ => HandCategory::FiveOfAKind,
+        }
+    }
+
+    /// Converts the value into the equivalent `HandRank` as produced by `evaluate_best`
+    pub fn to_hand_rank(&self) -> HandRank {
+        let category = self.category();
+        let primary = rank_list(((self.0 >> RANK_BITS) as u64) & RANK_MASK);
+        let ranks = match category {
+            HandCategory::Straight | HandCategory::StraightFlush => {
+                // Only the top of the straight is held so fill in the rest, wrapping to the Ace
+                let top = primary[0].ordinal() + 13;
+                (0..5).map(|i| Rank::ranks()[(top - i) % 13]).collect()
+            }
+            _ => {
+                let mut ranks = primary;
+                ranks.extend(rank_list((self.0 as u64) & RANK_MASK));
+                ranks
+            }
+        };
+        HandRank::new(category, ranks)
+    }
+
+    /// Returns the equivalence class of the hand, a number from 1 for a royal flush to 7462 for the
+    /// worst high card hand. Every distinct poker hand value has its own class so hands which only
+    /// differ by suit share a class. Returns `None` if the value is not of five cards, e.g. it was
+    /// evaluated from fewer cards, or is five of a kind.
+    pub fn equivalence_class(&self) -> Option<u16> {
+        let classes = &tables().classes;
+        // Classes are held best first
+        classes.binary_search_by(|v| self.cmp(v)).ok().map(|index| (index + 1) as u16)
+    }
+
+    /// Returns the value of the hand for the equivalence class, or `None` if it is out of range
+    pub fn from_equivalence_class(class: u16) -> Option<HandValue> {
+        let index = (class as usize).checked_sub(1)?;
+        tables().classes.get(index).copied()
+    }
+
+    /// Returns an English description of the hand, e.g. "Two Pair, Kings and Fives, Ace kicker"
+    pub fn description(&self) -> String {
+        let rank = self.to_hand_rank();
+        let r = &rank.ranks;
+        match rank.category {
+            HandCategory::StraightFlush if r[0] == Rank::Ace => "Royal Flush".to_string(),
+            HandCategory::StraightFlush | HandCategory::Straight | HandCategory::Flush =>
+                format!("{}, {} high", rank.category, r[0].to_str()),
+            HandCategory::FourOfAKind | HandCategory::ThreeOfAKind | HandCategory::OnePair =>
+                format!("{}, {}, {} kicker", rank.category, r[0].to_plural_str(), r[1].to_str()),
+            HandCategory::FullHouse =>
+                format!("{}, {} over {}", rank.category, r[0].to_plural_str(), r[1].to_plural_str()),
+            HandCategory::TwoPair =>
+                format!("{}, {} and {}, {} kicker", rank.category, r[0].to_plural_str(), r[1].to_plural_str(), r[2].to_str()),
+            HandCategory::FiveOfAKind => format!("{}, {}", rank.category, r[0].to_plural_str()),
+            HandCategory::HighCard => format!("{}, {}", rank.category, r[0].to_str()),
+        }
+    }
+}
+
+/// Returns the ranks in a rank mask from highest to lowest
+fn rank_list(mask: u64) -> Vec<Rank> {
+    Rank::iterator().rev().filter(|r| mask & (1 << r.ordinal()) != 0).cloned().collect()
+}
+
+/// Keeps the highest `n` ranks in the mask
+fn top_ranks(mut mask: u64, n: u32) -> u64 {
+    while mask.count_ones() > n {
+        mask &= mask - 1;
+    }
+    mask
+}
+
+/// Returns the mask of the highest card of the best straight in the rank mask, or 0
+fn straight_top(mask: u64) -> u64 {
+    // Shift up by one and copy the Ace into the bottom so the wheel can be found
+    let m = (mask << 1) | ((mask >> 12) & 1);
+    let runs = m & (m >> 1) & (m >> 2) & (m >> 3) & (m >> 4);
+    if runs == 0 {
+        0
+    } else {
+        // The run starting at bit n finishes with the original rank n + 3
+        1 << (63 - runs.leading_zeros() + 3)
+    }
+}
+
+struct Tables {
+    /// Values of every suit mask of five or more ranks as a flush or straight flush
+    flushes: Vec<HandValue>,
+    /// Values of every mask of distinct ranks as a high card or straight
+    distinct: Vec<HandValue>,
+    /// Every distinct five card hand value from best to worst
+    classes: Vec<HandValue>,
+}
+
+fn tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        let size = 1 << RANK_BITS;
+        let mut flushes = vec![HandValue(0); size];
+        let mut distinct = vec![HandValue(0); size];
+        for mask in 0..size as u64 {
+            let top = straight_top(mask);
+            distinct[mask as usize] = if top != 0 {
+                HandValue::new(HandCategory::Straight, top, 0)
+            } else {
+                HandValue::new(HandCategory::HighCard, top_ranks(mask, 5), 0)
+            };
+            if mask.count_ones() >= 5 {
+                flushes[mask as usize] = if top != 0 {
+                    HandValue::new(HandCategory::StraightFlush, top, 0)
+                } else {
+                    HandValue::new(HandCategory::Flush, top_ranks(mask, 5), 0)
+                };
+            }
+        }
+        let mut tables = Tables { flushes, distinct, classes: Vec::new() };
+        tables.classes = equivalence_classes(&tables);
+        tables
+    })
+}
+
+/// Produces the value of every distinct five card hand, sorted from best to worst
+fn equivalence_classes(tables: &Tables) -> Vec<HandValue> {
+    let mut classes = Vec::with_capacity(7462);
+    // Every multiset of five ranks with no more than four of any rank, each rank taking the
+    // next suits in turn so that they never make a flush
+    let mut counts = [0usize; 13];
+    fn visit(tables: &Tables, counts: &mut [usize; 13], rank: usize, remaining: usize, classes: &mut Vec<HandValue>) {
+        if remaining == 0 {
+            let mut mask = 0u64;
+            let mut suit = 0;
+            for (rank, count) in counts.iter().enumerate() {
+                for _ in 0..*count {
+                    mask |= 1 << (suit % 4 * 13 + rank);
+                    suit += 1;
+                }
+            }
+            classes.push(evaluate_mask_with(tables, mask));
+            if counts.iter().all(|c| *c <= 1) {
+                // The same ranks all in the first suit make a flush
+                let flush = (0..13).filter(|r| counts[*r] == 1).fold(0, |m, r| m | (1 << r));
+                classes.push(evaluate_mask_with(tables, flush));
+            }
+        } else if rank < 13 {
+            for count in (0..=remaining.min(4)).rev() {
+                counts[rank] = count;
+                visit(tables, counts, rank + 1, remaining - count, classes);
+            }
+            counts[rank] = 0;
+        }
+    }
+    visit(tables, &mut counts, 0, 5, &mut classes);
+    classes.sort_by(|a, b| b.cmp(a));
+    classes
+}
+
+/// Returns a mask of cards where bit `n` is set for the card whose `Card::ordinal()` is `n`
+pub fn card_mask(cards: &[Card]) -> u64 {
+    cards.iter().fold(0, |mask, c| mask | (1 << c.ordinal()))
+}
+
+/// Evaluates the best poker hand from a set of cards using precomputed tables. This is
+/// equivalent to `evaluate_best` but is intended for simulations which need to evaluate many
+/// millions of hands. Up to seven cards make sense as a poker hand, and duplicate cards are only
+/// counted once. Jokers are ignored.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, HandCategory, evaluate_fast};
+/// let hand = hand!("AS", "AD", "3C", "3S", "7H", "8H", "3D");
+/// assert_eq!(evaluate_fast(hand.cards()).category(), HandCategory::FullHouse);
+/// # }
+/// ```
+pub fn evaluate_fast(cards: &[Card]) -> HandValue {
+    evaluate_mask(card_mask(cards))
+}
+
+/// Evaluates the best poker hand from a mask of cards as produced by `card_mask`
+pub fn evaluate_mask(mask: u64) -> HandValue {
+    evaluate_mask_with(tables(), mask)
+}
+
+/// Returns the equivalence class of exactly five cards, see `HandValue::equivalence_class`
+pub fn equivalence_class(cards: &[Card]) -> Result<u16, EvalError> {
+    poker::check_hand_size(cards, 5, 5)?;
+    poker::check_no_jokers(cards)?;
+    evaluate_fast(cards).equivalence_class().ok_or(EvalError::DuplicateCard)
+}
+
+fn evaluate_mask_with(tables: &Tables, mask: u64) -> HandValue {
+    let suits = [
+        mask & RANK_MASK,
+        (mask >> RANK_BITS) & RANK_MASK,
+        (mask >> (2 * RANK_BITS)) & RANK_MASK,
+        (mask >> (3 * RANK_BITS)) & RANK_MASK,
+    ];
+
+    // Add the suits together as bit slices so each rank has a count from 0 to 4
+    let (mut ones, mut twos, mut fours) = (0, 0, 0);
+    let mut flush = HandValue(0);
+    for suit in suits {
+        let carry = ones & suit;
+        ones ^= suit;
+        fours |= twos & carry;
+        twos ^= carry;
+        flush = flush.max(tables.flushes[suit as usize]);
+    }
+    let any = suits[0] | suits[1] | suits[2] | suits[3];
+    let trips = ones & twos;
+    let pairs = twos & !ones;
+
+    let value = if fours != 0 {
+        let quads = top_ranks(fours, 1);
+        HandValue::new(HandCategory::FourOfAKind, quads, top_ranks(any & !quads, 1))
+    } else if trips != 0 && (trips.count_ones() > 1 || pairs != 0) {
+        let three = top_ranks(trips, 1);
+        HandValue::new(HandCategory::FullHouse, three, top_ranks((trips & !three) | pairs, 1))
+    } else if tables.distinct[any as usize].category() == HandCategory::Straight {
+        tables.distinct[any as usize]
+    } else if trips != 0 {
+        HandValue::new(HandCategory::ThreeOfAKind, trips, top_ranks(any & !trips, 2))
+    } else if pairs.count_ones() > 1 {
+        let two = top_ranks(pairs, 2);
+        HandValue::new(HandCategory::TwoPair, two, top_ranks(any & !two, 1))
+    } else if pairs != 0 {
+        HandValue::new(HandCategory::OnePair, pairs, top_ranks(any & !pairs, 3))
+    } else {
+        tables.distinct[any as usize]
+    };
+    value.max(flush)
+}
@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+
+use super::*;
+
+/// The value of a low hand. Lower cards make a better low hand, however a `LowRank` is ordered
+/// so that the better hand compares greater, the same as a `HandRank`.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct LowRank {
+    /// The ranks from the most to the least significant, e.g. 8-6-4-2-A
+    pub ranks: Vec<Rank>,
+    /// Sorts with the best low hand first
+    key: Vec<usize>,
+}
+
+impl PartialOrd for LowRank {
+    fn partial_cmp(&self, other: &LowRank) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LowRank {
+    /// Compares so that the better low hand is greater
+    fn cmp(&self, other: &LowRank) -> Ordering {
+        other.key.cmp(&self.key)
+    }
+}
+
+/// The best low hand chosen from a larger set of cards.
+#[derive(Clone, Debug)]
+pub struct LowHand {
+    /// The value of the hand
+    pub rank: LowRank,
+    /// The cards which make the hand
+    pub cards: Vec<Card>,
+}
+
+/// Evaluates five cards as an eight-or-better low. A hand qualifies if it holds five different
+/// ranks of Eight or below with an Ace counting low as per `Rank::cmp_ace_low`.
+pub(crate) fn eight_or_better_five(cards: &[Card]) -> Option<LowRank> {
+    let mut ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
+    ranks.sort_by(|a, b| b.cmp_ace_low(a));
+    ranks.dedup();
+    if ranks.len() != 5 || ranks[0].cmp_ace_low(&Rank::Eight) == Ordering::Greater {
+        return None;
+    }
+    let key = ranks.iter().map(|r| r.ace_low_ordinal()).collect();
+    Some(LowRank { ranks, key })
+}
+
+/// Evaluates the best eight-or-better low hand from five to seven cards, e.g. for stud hi-lo.
+/// Returns `None` if no five cards qualify for the low.
+pub fn evaluate_eight_or_better(cards: &[Card]) -> Result<Option<LowHand>, EvalError> {
+    poker::check_hand_size(cards, 5, 7)?;
+    poker::check_no_jokers(cards)?;
+    let mut best: Option<LowHand> = None;
+    poker::for_each_combination(cards, 5, |five| {
+        choose_low(&mut best, eight_or_better_five(five), five);
+    });
+    Ok(best)
+}
+
+/// Evaluates five cards as an ace-to-five low. Aces are low, straights and flushes do not count
+/// and pairs count against the hand, so the best hand is 5-4-3-2-A.
+fn ace_to_five_five(cards: &[Card]) -> LowRank {
+    // Group the ranks by count, most frequent and then highest first
+    let mut counts = [0usize; 13];
+    cards.iter().for_each(|c| counts[c.rank.ace_low_ordinal()] += 1);
+    let mut groups: Vec<(usize, Rank)> = cards.iter().map(|c| (counts[c.rank.ace_low_ordinal()], c.rank)).collect();
+    groups.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp_ace_low(&a.1)));
+    groups.dedup();
+
+    // The pairing decides first, from no pair up to five of a kind
+    let pairing = match (groups[0].0, groups.get(1).map_or(0, |g| g.0)) {
+        (1, _) => HandCategory::HighCard,
+        (2, 1) => HandCategory::OnePair,
+        (2, _) => HandCategory::TwoPair,
+        (3, 1) => HandCategory::ThreeOfAKind,
+        (3, _) => HandCategory::FullHouse,
+        (4, _) => HandCategory::FourOfAKind,
+        _ => HandCategory::FiveOfAKind,
+    };
+    let ranks: Vec<Rank> = groups.iter().map(|g| g.1).collect();
+    let mut key = vec![pairing.ordinal()];
+    key.extend(ranks.iter().map(|r| r.ace_low_ordinal()));
+    LowRank { ranks, key }
+}
+
+/// Evaluates five cards as a deuce-to-seven low. Aces are high and straights and flushes count
+/// against the hand, so the best hand is 7-5-4-3-2 of mixed suits.
+fn deuce_to_seven_five(cards: &[Card]) -> LowRank {
+    let high = poker::rank_five(cards, false);
+    let mut key = vec![high.category.ordinal()];
+    key.extend(high.ranks.iter().map(|r| r.ordinal()));
+    LowRank { ranks: high.ranks, key }
+}
+
+/// Evaluates the best ace-to-five low hand from five to seven cards, e.g. for Razz.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, Rank, evaluate_ace_to_five};
+/// let hand = hand!("AS", "2S", "3S", "4S", "5S", "KD", "KC");
+/// let low = evaluate_ace_to_five(hand.cards()).unwrap();
+/// assert_eq!(low.rank.ranks[0], Rank::Five);
+/// # }
+/// ```
+pub fn evaluate_ace_to_five(cards: &[Card]) -> Result<LowHand, EvalError> {
+    poker::check_hand_size(cards, 5, 7)?;
+    poker::check_no_jokers(cards)?;
+    let mut best: Option<LowHand> = None;
+    poker::for_each_combination(cards, 5, |five| {
+        choose_low(&mut best, Some(ace_to_five_five(five)), five);
+    });
+    Ok(best.unwrap())
+}
+
+/// Evaluates the best deuce-to-seven low hand from five to seven cards.
+pub fn evaluate_deuce_to_seven(cards: &[Card]) -> Result<LowHand, EvalError> {
+    poker::check_hand_size(cards, 5, 7)?;
+    poker::check_no_jokers(cards)?;
+    let mut best: Option<LowHand> = None;
+    poker::for_each_combination(cards, 5, |five| {
+        choose_low(&mut best, Some(deuce_to_seven_five(five)), five);
+    });
+    Ok(best.unwrap())
+}
+
+/// Evaluates four cards as a Badugi hand. The hand plays the most cards which are all of different
+/// suits and different ranks, so a four card hand (a badugi) beats any three card hand and so on.
+/// Between hands of the same size the lowest wins, with Aces low.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, evaluate_badugi};
+/// let badugi = evaluate_badugi(hand!("KS", "QH", "JD", "TC").cards()).unwrap();
+/// let three_card = evaluate_badugi(hand!("AS", "2H", "3D", "4D").cards()).unwrap();
+/// assert_eq!(badugi.cards.len(), 4);
+/// assert_eq!(three_card.cards.len(), 3);
+/// assert!(badugi.rank > three_card.rank);
+/// # }
+/// ```
+pub fn evaluate_badugi(cards: &[Card]) -> Result<LowHand, EvalError> {
+    poker::check_hand_size(cards, 4, 4)?;
+    poker::check_no_jokers(cards)?;
+    let mut best: Option<LowHand> = None;
+    for size in (1..=4).rev() {
+        poker::for_each_combination(cards, size, |subset| {
+            let rainbow = subset.iter().enumerate().all(|(i, a)| {
+                subset[i + 1..].iter().all(|b| a.suit != b.suit && a.rank != b.rank)
+            });
+            if rainbow {
+                let mut ranks: Vec<Rank> = subset.iter().map(|c| c.rank).collect();
+                ranks.sort_by(|a, b| b.cmp_ace_low(a));
+                let mut key = vec![4 - size];
+                key.extend(ranks.iter().map(|r| r.ace_low_ordinal()));
+                choose_low(&mut best, Some(LowRank { ranks, key }), subset);
+            }
+        });
+        if best.is_some() {
+            break;
+        }
+    }
+    Ok(best.unwrap())
+}
+
+/// Replaces the best low hand with the cards if they make a better one
+pub(crate) fn choose_low(best: &mut Option<LowHand>, rank: Option<LowRank>, cards: &[Card]) {
+    if let Some(rank) = rank {
+        if best.as_ref().map_or(true, |b| rank > b.rank) {
+            *best = Some(LowHand { rank, cards: cards.to_vec() });
+        }
+    }
+}
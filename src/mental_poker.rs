@@ -0,0 +1,184 @@
+use num_bigint::{BigUint, RandBigInt};
+use rand::seq::SliceRandom;
+use rand::{CryptoRng, Rng};
+
+use super::*;
+
+/// The 2048-bit safe prime of RFC 3526 group 14, in hex
+const RFC_3526_PRIME: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B139B22514A08798E3404DD",
+    "EF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F",
+    "83655D23DCA3AD961C62F356208552BB9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF6955817183995497CEA956AE515D2261898FA0510",
+    "15728E5A8AACAA68FFFFFFFFFFFFFFFF");
+
+/// Returns the 2048-bit safe prime from RFC 3526, a sensible shared prime for mental poker
+pub fn sra_prime() -> BigUint {
+    BigUint::parse_bytes(RFC_3526_PRIME.as_bytes(), 16).unwrap()
+}
+
+/// Encodes the card as a number modulo the prime. Cards are encoded as quadratic residues, the
+/// square of the card's ordinal plus 2, because SRA encryption would otherwise leak whether a card
+/// is a quadratic residue.
+fn encode_card(card: &Card, prime: &BigUint) -> BigUint {
+    BigUint::from(card.ordinal() as u32 + 2).pow(2) % prime
+}
+
+/// Decodes a number made by `encode_card`
+fn decode_card(value: &BigUint, prime: &BigUint) -> Option<Card> {
+    (0..Card::all_cards().len() + Card::jokers().len())
+        .filter_map(Card::from_ordinal)
+        .find(|c| encode_card(c, prime) == *value)
+}
+
+/// A key for SRA encryption, a pair of exponents which are inverses modulo the prime minus one.
+/// Encrypting with one key then another gives the same result as the other way round, which is
+/// what lets several parties encrypt the same deck.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SraKey {
+    encrypt: BigUint,
+    decrypt: BigUint,
+}
+
+impl SraKey {
+    /// Generates a random key for the prime
+    pub fn generate<R: Rng + CryptoRng>(prime: &BigUint, rng: &mut R) -> SraKey {
+        let order = prime - 1u32;
+        loop {
+            let encrypt = rng.gen_biguint_range(&BigUint::from(3u32), &order);
+            if let Some(decrypt) = encrypt.modinv(&order) {
+                return SraKey { encrypt, decrypt };
+            }
+        }
+    }
+
+    /// Encrypts the value modulo the prime
+    pub fn encrypt(&self, value: &BigUint, prime: &BigUint) -> BigUint {
+        value.modpow(&self.encrypt, prime)
+    }
+
+    /// Decrypts the value modulo the prime
+    pub fn decrypt(&self, value: &BigUint, prime: &BigUint) -> BigUint {
+        value.modpow(&self.decrypt, prime)
+    }
+}
+
+/// A deck of cards encrypted by several parties, so that no single party knows its order. The
+/// cards are shuffled and encrypted in two rounds:
+///
+/// 1. Each party in turn calls `SraParty::shuffle_deck()` to encrypt every card with its shuffle
+///    key and shuffle the deck.
+/// 2. Once every party has shuffled, each party in turn calls `SraParty::lock_deck()` to swap its
+///    shuffle key for a different key for each card.
+///
+/// A card is then revealed by gathering every party's key for that card. To deal a card to a
+/// player, the other parties send their keys to that player alone, who adds their own key and
+/// calls `reveal_card()`. Cards for everyone to see, such as a board, have every key published.
+///
+/// This protects the deck from curious parties but does not detect a party which cheats, e.g. by
+/// sending the wrong key.
+///
+/// # Example
+///
+/// ```
+/// use deckofcards::{BigUint, EncryptedDeck, SraParty};
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+/// // A small safe prime keeps the example quick, real games should use sra_prime()
+/// let prime = BigUint::parse_bytes(b"a52e4cc6717c8c47eb957431347d967f7b20a939bea1b3c6416599f8ba14f3ab", 16).unwrap();
+/// let mut rng = ChaCha20Rng::seed_from_u64(19);
+/// let mut alice = SraParty::new(&prime, &mut rng);
+/// let mut bob = SraParty::new(&prime, &mut rng);
+/// let mut deck = EncryptedDeck::new(&prime);
+/// alice.shuffle_deck(&mut deck, &mut rng);
+/// bob.shuffle_deck(&mut deck, &mut rng);
+/// alice.lock_deck(&mut deck, &mut rng);
+/// bob.lock_deck(&mut deck, &mut rng);
+///
+/// // Bob sends his key for the first card to Alice so only she sees it
+/// let card = deck.reveal_card(0, &[alice.card_key(0).unwrap(), bob.card_key(0).unwrap()]).unwrap();
+/// assert!(!card.is_joker());
+/// ```
+#[derive(Clone, Debug)]
+pub struct EncryptedDeck {
+    prime: BigUint,
+    cards: Vec<BigUint>,
+}
+
+impl EncryptedDeck {
+    /// Creates an unencrypted deck of the standard 52 cards for the prime
+    pub fn new(prime: &BigUint) -> EncryptedDeck {
+        EncryptedDeck::from_cards(Card::all_cards(), prime)
+    }
+
+    /// Creates an unencrypted deck of the cards for the prime
+    pub fn from_cards(cards: &[Card], prime: &BigUint) -> EncryptedDeck {
+        EncryptedDeck { prime: prime.clone(), cards: cards.iter().map(|c| encode_card(c, prime)).collect() }
+    }
+
+    /// Returns the prime the deck is encrypted with
+    pub fn prime(&self) -> &BigUint {
+        &self.prime
+    }
+
+    /// Returns the encrypted cards, e.g. to send to the next party
+    pub fn cards(&self) -> &[BigUint] {
+        self.cards.as_slice()
+    }
+
+    /// Returns the number of cards in the deck
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Tests if the deck is empty
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Reveals the card at the index with every party's key for it. If the index is out of range or
+    /// the keys do not decrypt the card, the function will return an error.
+    pub fn reveal_card(&self, index: usize, keys: &[&SraKey]) -> Result<Card, RevealError> {
+        let value = self.cards.get(index).ok_or(RevealError::NoCard { index })?;
+        let value = keys.iter().fold(value.clone(), |v, k| k.decrypt(&v, &self.prime));
+        decode_card(&value, &self.prime).ok_or(RevealError::CannotDecrypt)
+    }
+}
+
+/// One party to a game of mental poker. Each party holds a shuffle key, used while the deck is
+/// shuffled, and then a key for each card once the deck is locked. See `EncryptedDeck` for the
+/// protocol.
+#[derive(Clone, Debug)]
+pub struct SraParty {
+    prime: BigUint,
+    shuffle_key: SraKey,
+    card_keys: Vec<SraKey>,
+}
+
+impl SraParty {
+    /// Creates a party with a random shuffle key for the prime
+    pub fn new<R: Rng + CryptoRng>(prime: &BigUint, rng: &mut R) -> SraParty {
+        SraParty { prime: prime.clone(), shuffle_key: SraKey::generate(prime, rng), card_keys: Vec::new() }
+    }
+
+    /// Encrypts every card of the deck with the shuffle key and shuffles it
+    pub fn shuffle_deck<R: Rng + CryptoRng>(&self, deck: &mut EncryptedDeck, rng: &mut R) {
+        deck.cards.iter_mut().for_each(|c| *c = self.shuffle_key.encrypt(c, &self.prime));
+        deck.cards.shuffle(rng);
+    }
+
+    /// Replaces the shuffle key on every card of the deck with a new key for each card. Every
+    /// party must have shuffled the deck first.
+    pub fn lock_deck<R: Rng + CryptoRng>(&mut self, deck: &mut EncryptedDeck, rng: &mut R) {
+        self.card_keys = (0..deck.len()).map(|_| SraKey::generate(&self.prime, rng)).collect();
+        for (card, key) in deck.cards.iter_mut().zip(&self.card_keys) {
+            *card = key.encrypt(&self.shuffle_key.decrypt(card, &self.prime), &self.prime);
+        }
+    }
+
+    /// Returns the key for the card at the index, to be shared with whoever the card is revealed to
+    pub fn card_key(&self, index: usize) -> Option<&SraKey> {
+        self.card_keys.get(index)
+    }
+}
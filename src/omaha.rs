@@ -0,0 +1,66 @@
+use super::*;
+
+/// Checks the hole cards and board are a legal Omaha hand
+fn check_omaha(hole: &[Card], board: &[Card]) -> Result<(), EvalError> {
+    if hole.len() < 4 || hole.len() > 6 {
+        return Err(EvalError::WrongHoleSize { min: 4, max: 6, found: hole.len() });
+    }
+    if board.len() < 3 || board.len() > 5 {
+        return Err(EvalError::WrongBoardSize { min: 3, max: 5, found: board.len() });
+    }
+    poker::check_no_jokers(hole)?;
+    poker::check_no_jokers(board)?;
+    poker::check_no_duplicates(&[hole, board].concat())
+}
+
+/// Calls the function with every legal Omaha hand, i.e. exactly two hole cards and three cards from
+/// the board.
+fn for_each_omaha_hand<F>(hole: &[Card], board: &[Card], mut f: F)
+    where F: FnMut(&[Card]) {
+    let mut five = [Card::new(Rank::Two, Suit::Spades); 5];
+    poker::for_each_combination(hole, 2, |two| {
+        five[..2].copy_from_slice(two);
+        poker::for_each_combination(board, 3, |three| {
+            five[2..].copy_from_slice(three);
+            f(&five);
+        });
+    });
+}
+
+/// Evaluates the best high hand in Omaha which must use exactly two of the four to six hole cards
+/// and exactly three cards from the board.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, HandCategory, evaluate_omaha};
+/// let hole = hand!("AS", "KS", "7D", "2C");
+/// let board = hand!("QS", "JS", "TS", "9D", "8D");
+/// let best = evaluate_omaha(hole.cards(), board.cards()).unwrap();
+/// assert_eq!(best.rank.category, HandCategory::StraightFlush);
+/// # }
+/// ```
+pub fn evaluate_omaha(hole: &[Card], board: &[Card]) -> Result<BestHand, EvalError> {
+    check_omaha(hole, board)?;
+    let mut best: Option<BestHand> = None;
+    for_each_omaha_hand(hole, board, |five| {
+        let rank = poker::rank_five(five, true);
+        if best.as_ref().map_or(true, |b| rank > b.rank) {
+            best = Some(BestHand { rank, cards: five.to_vec() });
+        }
+    });
+    Ok(best.unwrap())
+}
+
+/// Evaluates the best eight-or-better low hand in Omaha Hi-Lo, using exactly two hole cards and
+/// exactly three cards from the board. Returns `None` if there is no qualifying low.
+pub fn evaluate_omaha_low(hole: &[Card], board: &[Card]) -> Result<Option<LowHand>, EvalError> {
+    check_omaha(hole, board)?;
+    let mut best: Option<LowHand> = None;
+    for_each_omaha_hand(hole, board, |five| {
+        lowball::choose_low(&mut best, lowball::eight_or_better_five(five), five);
+    });
+    Ok(best)
+}
@@ -0,0 +1,126 @@
+use super::*;
+
+/// Returns the rank starting at the index and how many chars it takes. Ranks are a char in either
+/// case, e.g. 'A' or 'a', or "10" for Ten.
+fn rank_at(chars: &[char], i: usize) -> Option<(Rank, usize)> {
+    match chars.get(i..i + 2) {
+        Some(['1', '0']) => Some((Rank::Ten, 2)),
+        _ => chars.get(i).and_then(|c| Rank::from_char(c.to_ascii_uppercase()).ok()).map(|r| (r, 1)),
+    }
+}
+
+/// Returns the suit of the char, which is a letter in either case, e.g. 'S' or 's', or a unicode
+/// suit, e.g. '♠'
+fn suit_of(ch: char) -> Option<Suit> {
+    Suit::from_char(ch.to_ascii_uppercase()).or_else(|_| Suit::from_unicode(ch)).ok()
+}
+
+/// Reads a card in short form starting at the index, with the rank first, e.g. "As" or "10♥", the
+/// suit first, e.g. "♠A", or as a playing card glyph, e.g. "🂡". Returns the card and how many
+/// chars it takes. The chars end where the card must end, so a card which runs past them is the
+/// wrong length.
+fn card_at(chars: &[char], i: usize, input: &str) -> Result<(Card, usize), ParseError> {
+    if let Some(card) = chars.get(i).and_then(|c| Card::from_unicode(*c)) {
+        return Ok((card, 1));
+    }
+    if let Some((rank, n)) = rank_at(chars, i) {
+        return match chars.get(i + n) {
+            Some(c) => match suit_of(*c) {
+                Some(suit) => Ok((Card::new(rank, suit), n + 1)),
+                None => Err(ParseError::InvalidSuit { input: input.to_string(), position: i + n, found: *c }),
+            },
+            None => Err(ParseError::WrongLength { input: input.to_string(), position: i, expected: n + 1, found: chars.len() - i }),
+        };
+    }
+    match chars.get(i) {
+        Some(c) if suit_of(*c).is_some() => match (rank_at(chars, i + 1), chars.get(i + 1)) {
+            (Some((rank, n)), _) => Ok((Card::new(rank, suit_of(*c).unwrap()), n + 1)),
+            (None, Some(r)) => Err(ParseError::InvalidRank { input: input.to_string(), position: i + 1, found: *r }),
+            (None, None) => Err(ParseError::WrongLength { input: input.to_string(), position: i, expected: 2, found: chars.len() - i }),
+        },
+        Some(c) => Err(ParseError::InvalidRank { input: input.to_string(), position: i, found: *c }),
+        None => Err(ParseError::WrongLength { input: input.to_string(), position: i, expected: 2, found: chars.len() - i }),
+    }
+}
+
+/// Parses a card from its name, e.g. "Ace of Spades", "ten of hearts" or "Red Joker"
+fn card_from_name(s: &str) -> Option<Card> {
+    let words: Vec<String> = s.split_whitespace().map(|w| w.to_lowercase()).collect();
+    match words.as_slice() {
+        [rank, of, suit] if of == "of" => {
+            let rank = Rank::ranks().iter()
+                .find(|r| r.to_str().to_lowercase() == *rank)
+                .copied()
+                .or_else(|| rank_at(&rank.chars().collect::<Vec<char>>(), 0).filter(|(_, n)| *n == rank.chars().count()).map(|(r, _)| r))?;
+            let suit = Suit::suits().iter().find(|s| {
+                let name = s.to_str().to_lowercase();
+                name == *suit || name.strip_suffix('s') == Some(suit)
+            })?;
+            Some(Card::new(rank, *suit))
+        }
+        [colour, joker] if joker == "joker" => match colour.as_str() {
+            "red" => Some(Card::red_joker()),
+            "black" => Some(Card::black_joker()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Parses a single card in any of the forms `Card::from_str()` accepts
+pub(crate) fn parse_card(s: &str) -> Result<Card, ParseError> {
+    let trimmed = s.trim();
+    let offset = s.chars().count() - s.trim_start().chars().count();
+    if trimmed.contains(char::is_whitespace) {
+        return card_from_name(trimmed).ok_or_else(|| ParseError::InvalidName { input: s.to_string(), position: offset });
+    }
+    let chars: Vec<char> = trimmed.chars().collect();
+    let (card, n) = card_at(&chars, 0, trimmed).map_err(|e| e.within(s, offset))?;
+    if n != chars.len() {
+        return Err(ParseError::WrongLength { input: s.to_string(), position: offset, expected: n, found: chars.len() });
+    }
+    Ok(card)
+}
+
+/// Parses cards separated by commas, whitespace or nothing at all, e.g. "AS,KD", "As Kd" or
+/// "AsKd". Cards written by name must be separated by commas, e.g. "Ace of Spades, King of Hearts".
+pub(crate) fn parse_cards(s: &str) -> Result<Vec<Card>, ParseError> {
+    let mut cards = Vec::new();
+    if s.trim().is_empty() {
+        return Ok(cards);
+    }
+    let chars: Vec<char> = s.chars().collect();
+    let mut start = 0;
+    for item in s.split(',') {
+        let end = start + item.chars().count();
+        let item_start = start + item.chars().count() - item.trim_start().chars().count();
+        if item.trim().contains(char::is_whitespace) {
+            if let Some(card) = card_from_name(item) {
+                cards.push(card);
+                start = end + 1;
+                continue;
+            }
+            if item.split_whitespace().any(|w| w.eq_ignore_ascii_case("of") || w.eq_ignore_ascii_case("joker")) {
+                return Err(ParseError::InvalidName { input: s.to_string(), position: item_start });
+            }
+        }
+        // A run of short form cards with or without whitespace between them
+        let mut i = start;
+        let mut found = false;
+        while i < end {
+            if chars[i].is_whitespace() {
+                i += 1;
+                continue;
+            }
+            let (card, n) = card_at(&chars[..end], i, s)?;
+            cards.push(card);
+            found = true;
+            i += n;
+        }
+        if !found {
+            return Err(ParseError::WrongLength { input: s.to_string(), position: item_start, expected: 2, found: 0 });
+        }
+        start = end + 1;
+    }
+    Ok(cards)
+}
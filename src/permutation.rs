@@ -0,0 +1,105 @@
+use super::*;
+
+/// The number of bytes needed to hold an index into every order of 52 cards. There are 52!
+/// orders, slightly less than 2^226.
+pub const PERMUTATION_INDEX_BYTES: usize = 29;
+
+/// The index of an order of the 52 standard cards, from 0 to 52! - 1, as a big-endian integer
+pub type PermutationIndex = [u8; PERMUTATION_INDEX_BYTES];
+
+/// The number of base-62 digits in an order id. 62^38 is the smallest power of 62 above 52!.
+const ID_DIGITS: usize = 38;
+
+const BASE62_DIGITS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Multiplies the big-endian integer by m and adds a, returning the overflow
+fn mul_add(n: &mut [u8], m: u32, a: u32) -> u32 {
+    let mut carry = a;
+    for byte in n.iter_mut().rev() {
+        let value = *byte as u32 * m + carry;
+        *byte = value as u8;
+        carry = value >> 8;
+    }
+    carry
+}
+
+/// Divides the big-endian integer by d, returning the remainder
+fn div_rem(n: &mut [u8], d: u32) -> u32 {
+    let mut remainder = 0;
+    for byte in n.iter_mut() {
+        let value = (remainder << 8) | *byte as u32;
+        *byte = (value / d) as u8;
+        remainder = value % d;
+    }
+    remainder
+}
+
+/// Returns 52!, one more than the largest index
+fn orders() -> PermutationIndex {
+    let mut n = [0; PERMUTATION_INDEX_BYTES];
+    n[PERMUTATION_INDEX_BYTES - 1] = 1;
+    (2..=52).for_each(|m| { mul_add(&mut n, m, 0); });
+    n
+}
+
+/// Returns the index of the order of the cards, which must be the 52 standard cards each exactly
+/// once. The cards in the order of `Card::all_cards()` are index 0.
+///
+/// The index is the Lehmer code of the order, i.e. for each card the number of cards after it
+/// with a lower ordinal, read as a number whose digits are in base 52, 51, 50 and so on.
+pub fn permutation_index(cards: &[Card]) -> Result<PermutationIndex, PermutationError> {
+    let all = Card::all_cards();
+    if cards.len() != all.len() || CardSet::from_cards(cards) != CardSet::all() {
+        return Err(PermutationError::NotStandardCards);
+    }
+    let mut index = [0; PERMUTATION_INDEX_BYTES];
+    for (i, card) in cards.iter().enumerate() {
+        let lower = cards[i + 1..].iter().filter(|c| c.ordinal() < card.ordinal()).count();
+        mul_add(&mut index, (all.len() - i) as u32, lower as u32);
+    }
+    Ok(index)
+}
+
+/// Returns the order of the 52 standard cards with the index. If the index is not below 52! the
+/// function will return an error.
+pub fn permutation_from_index(index: &PermutationIndex) -> Result<Vec<Card>, PermutationError> {
+    if *index >= orders() {
+        return Err(PermutationError::IndexOutOfRange);
+    }
+    let all = Card::all_cards();
+    let mut n = *index;
+    let mut lehmer = vec![0; all.len()];
+    for (i, digit) in lehmer.iter_mut().enumerate().rev() {
+        *digit = div_rem(&mut n, (all.len() - i) as u32) as usize;
+    }
+    let mut remaining = all.to_vec();
+    Ok(lehmer.iter().map(|d| remaining.remove(*d)).collect())
+}
+
+/// Formats the index as a fixed length string of 38 base-62 digits, 0-9, A-Z then a-z
+pub fn permutation_index_to_id(index: &PermutationIndex) -> String {
+    let mut n = *index;
+    let mut digits: Vec<u8> = (0..ID_DIGITS).map(|_| BASE62_DIGITS[div_rem(&mut n, 62) as usize]).collect();
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+/// Parses an index from a string of 38 base-62 digits made by `permutation_index_to_id`. If the
+/// string is malformed or the index is not below 52! the function will return an error.
+pub fn permutation_index_from_id(id: &str) -> Result<PermutationIndex, PermutationError> {
+    let length = id.chars().count();
+    if length != ID_DIGITS {
+        return Err(PermutationError::WrongIdLength { found: length });
+    }
+    let mut index = [0; PERMUTATION_INDEX_BYTES];
+    for c in id.chars() {
+        let digit = BASE62_DIGITS.iter().position(|d| *d as char == c).ok_or(PermutationError::InvalidIdChar { found: c })?;
+        if mul_add(&mut index, 62, digit as u32) != 0 {
+            return Err(PermutationError::IndexOutOfRange);
+        }
+    }
+    if index >= orders() {
+        return Err(PermutationError::IndexOutOfRange);
+    }
+    Ok(index)
+}
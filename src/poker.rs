@@ -0,0 +1,254 @@
+use std::fmt;
+
+use super::*;
+
+/// The category of a poker hand, ordered from the weakest to the strongest.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum HandCategory {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    /// Only possible with wild cards or cards from more than one deck
+    FiveOfAKind,
+}
+
+impl fmt::Display for HandCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+impl HandCategory {
+    /// Returns an ordinal for the category, 0 being a high card
+    pub fn ordinal(&self) -> usize {
+        *self as usize
+    }
+
+    /// Returns an English name for the category, e.g. "Full House"
+    pub fn to_str(&self) -> &'static str {
+        match *self {
+            HandCategory::HighCard => "High Card",
+            HandCategory::OnePair => "One Pair",
+            HandCategory::TwoPair => "Two Pair",
+            HandCategory::ThreeOfAKind => "Three of a Kind",
+            HandCategory::Straight => "Straight",
+            HandCategory::Flush => "Flush",
+            HandCategory::FullHouse => "Full House",
+            HandCategory::FourOfAKind => "Four of a Kind",
+            HandCategory::StraightFlush => "Straight Flush",
+            HandCategory::FiveOfAKind => "Five of a Kind",
+        }
+    }
+}
+
+/// The value of a five card poker hand. A `HandRank` consists of the `HandCategory` and the ranks
+/// that decide between hands of the same category, so a better hand always compares greater.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, HandCategory, evaluate_five};
+/// let hand = hand!("KS", "KD", "5H", "5C", "AS");
+/// let rank = evaluate_five(hand.cards()).unwrap();
+/// assert_eq!(rank.category, HandCategory::TwoPair);
+/// # }
+/// ```
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct HandRank {
+    /// The category of the hand, e.g. Two Pair
+    pub category: HandCategory,
+    /// The ranks in order of significance, e.g. the higher pair, the lower pair and then the kicker
+    /// for Two Pair. A straight holds every rank from the highest, so a wheel is Five to Ace.
+    pub ranks: Vec<Rank>,
+}
+
+impl HandRank {
+    /// Creates a hand rank from the category and ranks in order of significance
+    pub fn new(category: HandCategory, ranks: Vec<Rank>) -> HandRank {
+        HandRank {
+            category,
+            ranks,
+        }
+    }
+}
+
+/// The best five card poker hand chosen from a larger set of cards.
+#[derive(Clone, Debug)]
+pub struct BestHand {
+    /// The value of the hand
+    pub rank: HandRank,
+    /// The five cards which make the hand
+    pub cards: Vec<Card>,
+}
+
+/// Evaluates exactly five cards as a poker hand. An Ace may play low in a straight (a wheel), in
+/// which case it is ordered as per `Rank::cmp_ace_low`. The cards must all be different.
+pub fn evaluate_five(cards: &[Card]) -> Result<HandRank, EvalError> {
+    check_hand_size(cards, 5, 5)?;
+    check_no_jokers(cards)?;
+    check_no_duplicates(cards)?;
+    Ok(rank_five(cards, true))
+}
+
+/// Evaluates a `Hand`, or anything else which implements `Cards`, of exactly five cards as a poker
+/// hand, see `evaluate_five`
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{HandCategory, evaluate_hand};
+/// let rank = evaluate_hand(&hand!("KS", "KD", "5H", "5C", "AS")).unwrap();
+/// assert_eq!(rank.category, HandCategory::TwoPair);
+/// # }
+/// ```
+pub fn evaluate_hand<C: Cards>(hand: &C) -> Result<HandRank, EvalError> {
+    evaluate_five(hand.cards())
+}
+
+/// Checks that none of the cards are Jokers, which must stand for some other card before a hand
+/// can be evaluated
+pub(crate) fn check_no_jokers(cards: &[Card]) -> Result<(), EvalError> {
+    if cards.iter().any(|c| c.is_joker()) {
+        Err(EvalError::Joker)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that no card appears more than once, e.g. from a shoe of several decks
+pub(crate) fn check_no_duplicates(cards: &[Card]) -> Result<(), EvalError> {
+    if CardSet::from_cards(cards).len() != cards.len() {
+        Err(EvalError::DuplicateCard)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that there are between `min` and `max` cards to evaluate
+pub(crate) fn check_hand_size(cards: &[Card], min: usize, max: usize) -> Result<(), EvalError> {
+    if cards.len() < min || cards.len() > max {
+        Err(EvalError::WrongHandSize { min, max, found: cards.len() })
+    } else {
+        Ok(())
+    }
+}
+
+/// Evaluates five cards as a poker hand, optionally allowing the Ace to play low in a straight
+pub(crate) fn rank_five(cards: &[Card], ace_low_straight: bool) -> HandRank {
+    // Group the ranks by count, most frequent and then highest first
+    let mut counts = [0usize; 13];
+    cards.iter().for_each(|c| counts[c.rank.ordinal()] += 1);
+    let mut groups: Vec<(usize, Rank)> = Rank::iterator()
+        .filter(|r| counts[r.ordinal()] > 0)
+        .map(|r| (counts[r.ordinal()], *r))
+        .collect();
+    groups.sort_by(|a, b| b.cmp(a));
+    let ranks: Vec<Rank> = groups.iter().map(|g| g.1).collect();
+
+    let flush = cards.iter().all(|c| c.suit == cards[0].suit);
+    let straight = if ranks.len() == 5 { straight_ranks(&ranks, ace_low_straight) } else { None };
+
+    // Five of a kind needs a repeated card, which only the lowball evaluators allow
+    let second = groups.get(1).map_or(0, |g| g.0);
+    let category = match (straight.is_some(), flush, groups[0].0, second) {
+        (_, _, 5, _) => HandCategory::FiveOfAKind,
+        (true, true, _, _) => HandCategory::StraightFlush,
+        (_, _, 4, _) => HandCategory::FourOfAKind,
+        (_, _, 3, 2) => HandCategory::FullHouse,
+        (_, true, _, _) => HandCategory::Flush,
+        (true, _, _, _) => HandCategory::Straight,
+        (_, _, 3, _) => HandCategory::ThreeOfAKind,
+        (_, _, 2, 2) => HandCategory::TwoPair,
+        (_, _, 2, _) => HandCategory::OnePair,
+        _ => HandCategory::HighCard,
+    };
+
+    HandRank::new(category, straight.unwrap_or(ranks))
+}
+
+/// Tests if the distinct ranks, sorted high to low, make a straight and if so returns them in order
+/// of significance. The Ace is tried high and then, if allowed, low.
+fn straight_ranks(ranks: &[Rank], ace_low: bool) -> Option<Vec<Rank>> {
+    let is_run = |ranks: &[Rank], ordinal: fn(&Rank) -> usize| {
+        ranks.windows(2).all(|w| ordinal(&w[0]) == ordinal(&w[1]) + 1)
+    };
+    if is_run(ranks, Rank::ordinal) {
+        return Some(ranks.to_vec());
+    }
+    if !ace_low {
+        return None;
+    }
+    let mut ace_low = ranks.to_vec();
+    ace_low.sort_by(|a, b| b.cmp_ace_low(a));
+    if is_run(&ace_low, Rank::ace_low_ordinal) {
+        Some(ace_low)
+    } else {
+        None
+    }
+}
+
+/// Evaluates the best five card poker hand from five to seven cards, e.g. two hole cards and a
+/// board in hold'em or seven cards in stud. The cards must all be different. When several choices
+/// of five cards make the same hand, the first one found is returned.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, HandCategory, evaluate_best};
+/// let hand = hand!("AS", "KS", "QS", "2D", "JS", "TS", "2C");
+/// let best = evaluate_best(hand.cards()).unwrap();
+/// assert_eq!(best.rank.category, HandCategory::StraightFlush);
+/// # }
+/// ```
+pub fn evaluate_best(cards: &[Card]) -> Result<BestHand, EvalError> {
+    check_hand_size(cards, 5, 7)?;
+    check_no_jokers(cards)?;
+    check_no_duplicates(cards)?;
+    let mut best: Option<BestHand> = None;
+    for_each_combination(cards, 5, |five| {
+        let rank = rank_five(five, true);
+        if best.as_ref().map_or(true, |b| rank > b.rank) {
+            best = Some(BestHand { rank, cards: five.to_vec() });
+        }
+    });
+    Ok(best.unwrap())
+}
+
+/// Calls the function with every combination of `k` cards from the slice. Combinations are
+/// visited in lexicographic order of their positions in the slice.
+pub(crate) fn for_each_combination<F>(cards: &[Card], k: usize, mut f: F)
+    where F: FnMut(&[Card]) {
+    let n = cards.len();
+    if k > n {
+        return;
+    }
+    let mut indices: Vec<usize> = (0..k).collect();
+    let mut combination: Vec<Card> = indices.iter().map(|i| cards[*i]).collect();
+    loop {
+        f(&combination);
+        // Find the rightmost index which can still be advanced
+        let i = match (0..k).rev().find(|i| indices[*i] != i + n - k) {
+            Some(i) => i,
+            None => return,
+        };
+        indices[i] += 1;
+        for j in i + 1..k {
+            indices[j] = indices[j - 1] + 1;
+        }
+        for j in i..k {
+            combination[j] = cards[indices[j]];
+        }
+    }
+}
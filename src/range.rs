@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use super::*;
+
+/// A two card starting hand, e.g. the Ace of Spades and King of Diamonds, with a weight which is
+/// the proportion of the time the hand is played.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Combo {
+    /// The two cards, highest rank first
+    pub cards: [Card; 2],
+    /// The weight from 0 to 1
+    pub weight: f64,
+}
+
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.cards[0], self.cards[1])
+    }
+}
+
+impl Combo {
+    /// Creates a combo with the cards in order of rank
+    pub fn new(c1: Card, c2: Card, weight: f64) -> Combo {
+        let mut cards = [c1, c2];
+        cards.sort_by(|a, b| a.cmp_desc_rank_then_suit(b));
+        Combo { cards, weight }
+    }
+
+    /// Tests if either card of the combo is one of the cards
+    pub fn is_blocked_by(&self, cards: &[Card]) -> bool {
+        cards.iter().any(|c| self.cards.contains(c))
+    }
+
+    /// Returns the ordinals of the cards, which identify the combo regardless of weight
+    fn key(&self) -> (usize, usize) {
+        (self.cards[0].ordinal(), self.cards[1].ordinal())
+    }
+}
+
+/// Whether a starting hand is suited, offsuit or either
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Suitedness {
+    Any,
+    Suited,
+    Offsuit,
+}
+
+/// A starting hand shape such as "AKs" or "TT"
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Shape {
+    high: Rank,
+    low: Rank,
+    suitedness: Suitedness,
+}
+
+impl Shape {
+    fn parse(s: &str) -> Result<Shape, RangeError> {
+        let chars: Vec<char> = s.chars().collect();
+        if chars.len() < 2 || chars.len() > 3 {
+            return Err(RangeError::InvalidHand { item: s.to_string() });
+        }
+        let rank = |ch: char| match Rank::from_char(ch.to_ascii_uppercase()) {
+            Ok(rank) if !rank.is_joker() => Ok(rank),
+            _ => Err(RangeError::InvalidRank { item: s.to_string(), found: ch }),
+        };
+        let (r1, r2) = (rank(chars[0])?, rank(chars[1])?);
+        let suitedness = match chars.get(2) {
+            None => Suitedness::Any,
+            Some('s' | 'S') => Suitedness::Suited,
+            Some('o' | 'O') => Suitedness::Offsuit,
+            Some(ch) => return Err(RangeError::InvalidSuitedness { item: s.to_string(), found: *ch }),
+        };
+        if r1 == r2 && suitedness != Suitedness::Any {
+            return Err(RangeError::SuitedPair { item: s.to_string() });
+        }
+        Ok(Shape { high: r1.max(r2), low: r1.min(r2), suitedness })
+    }
+
+    fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    fn gap(&self) -> usize {
+        self.high.ordinal() - self.low.ordinal()
+    }
+
+    /// Returns the shape with the ranks moved up or down by the given amounts
+    fn shift(&self, high: usize, low: usize) -> Shape {
+        Shape { high: Rank::ranks()[high], low: Rank::ranks()[low], suitedness: self.suitedness }
+    }
+
+    /// Expands the shape into every combo of cards
+    fn combos(&self, weight: f64, result: &mut Vec<Combo>) {
+        for s1 in Suit::iterator() {
+            for s2 in Suit::iterator() {
+                // Pairs and suited hands would otherwise be counted twice
+                if self.is_pair() && s1 >= s2 {
+                    continue;
+                }
+                let keep = match self.suitedness {
+                    Suitedness::Any => true,
+                    Suitedness::Suited => s1 == s2,
+                    Suitedness::Offsuit => s1 != s2,
+                };
+                if keep {
+                    result.push(Combo::new(Card::new(self.high, *s1), Card::new(self.low, *s2), weight));
+                }
+            }
+        }
+    }
+
+    /// Expands "+" notation. Pairs go up to Aces, connectors go up to AK and other hands
+    /// increase the low card up to one below the high card, e.g. "A9s+" is A9s to AKs.
+    fn plus(&self) -> Vec<Shape> {
+        let (h, l) = (self.high.ordinal(), self.low.ordinal());
+        if self.is_pair() {
+            (l..13).map(|r| self.shift(r, r)).collect()
+        } else if self.gap() == 1 {
+            (l..12).map(|r| self.shift(r + 1, r)).collect()
+        } else {
+            (l..h).map(|r| self.shift(h, r)).collect()
+        }
+    }
+
+    /// Expands "-" notation between two shapes of the item, e.g. "A5s-A2s", "TT-77" or "76s-43s"
+    fn span(&self, other: &Shape, item: &str) -> Result<Vec<Shape>, RangeError> {
+        if self.suitedness != other.suitedness || self.is_pair() != other.is_pair() {
+            return Err(RangeError::InvalidSpan { item: item.to_string() });
+        }
+        let (from, to) = if self.low <= other.low { (self, other) } else { (other, self) };
+        let (l1, l2) = (from.low.ordinal(), to.low.ordinal());
+        if self.is_pair() {
+            Ok((l1..=l2).map(|r| self.shift(r, r)).collect())
+        } else if from.high == to.high {
+            Ok((l1..=l2).map(|r| self.shift(from.high.ordinal(), r)).collect())
+        } else if from.gap() == to.gap() {
+            Ok((l1..=l2).map(|r| self.shift(r + from.gap(), r)).collect())
+        } else {
+            Err(RangeError::InvalidSpan { item: item.to_string() })
+        }
+    }
+}
+
+/// A range of two card starting hands, such as might be held by an opponent. Ranges are parsed
+/// from standard notation, a comma separated list of:
+///
+/// * Pairs, e.g. "TT", "TT+" for tens or better, "TT-77" for sevens to tens
+/// * Suited or offsuit hands, e.g. "AKs", "KQo", or "AK" for both
+/// * Hands with a better kicker, e.g. "A9s+" for A9s to AKs, or connectors, e.g. "76s+" for 76s to AKs
+/// * Spans, e.g. "A5s-A2s" or "76s-43s"
+/// * Exact cards, each a rank then a suit, e.g. "AsKd"
+///
+/// Any item may be followed by a weight, e.g. "AKo:0.5" to play it half the time.
+///
+/// # Example
+///
+/// ```
+/// use deckofcards::{Card, Range};
+/// let mut range = Range::parse("TT+, AKs, A5s-A2s, KQo, 76s+").unwrap();
+/// // AKs is also part of 76s+ but is only counted once
+/// assert_eq!(range.len(), 30 + 4 + 16 + 12 + 28);
+/// range.remove_blocked(&["AS".parse::<Card>().unwrap()]);
+/// assert_eq!(range.len(), 90 - 3 - 1 - 4);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Range {
+    combos: Vec<Combo>,
+    /// The position of each combo in `combos`
+    index: HashMap<(usize, usize), usize>,
+}
+
+impl Range {
+    /// Creates an empty range
+    pub fn new() -> Range {
+        Range::default()
+    }
+
+    /// Parses a range from standard range notation
+    pub fn parse(s: &str) -> Result<Range, RangeError> {
+        let mut range = Range::new();
+        for item in s.split(',').map(|i| i.trim()).filter(|i| !i.is_empty()) {
+            let (hand, weight) = match item.split_once(':') {
+                Some((hand, weight)) => {
+                    let weight = match weight.trim().parse::<f64>() {
+                        Ok(weight) if (0.0..=1.0).contains(&weight) => weight,
+                        _ => return Err(RangeError::InvalidWeight { item: item.to_string() }),
+                    };
+                    (hand.trim(), weight)
+                }
+                None => (item, 1.0),
+            };
+            range.push_item(hand, weight)?;
+        }
+        Ok(range)
+    }
+
+    fn push_item(&mut self, item: &str, weight: f64) -> Result<(), RangeError> {
+        let shapes = if let Some(base) = item.strip_suffix('+') {
+            Shape::parse(base)?.plus()
+        } else if let Some((from, to)) = item.split_once('-') {
+            Shape::parse(from)?.span(&Shape::parse(to)?, item)?
+        } else if item.chars().count() == 4 {
+            // Exact cards are always rank then suit, e.g. "AsKd"
+            let chars: Vec<char> = item.chars().collect();
+            let invalid = || RangeError::InvalidCards { item: item.to_string() };
+            let card = |i: usize| match (Rank::from_char(chars[i].to_ascii_uppercase()), Suit::from_char(chars[i + 1].to_ascii_uppercase())) {
+                (Ok(rank), Ok(suit)) if !rank.is_joker() => Ok(Card::new(rank, suit)),
+                _ => Err(invalid()),
+            };
+            let (c1, c2) = (card(0)?, card(2)?);
+            if c1 == c2 {
+                return Err(invalid());
+            }
+            self.push_combo(Combo::new(c1, c2, weight));
+            return Ok(());
+        } else {
+            vec![Shape::parse(item)?]
+        };
+        let mut combos = Vec::new();
+        shapes.iter().for_each(|s| s.combos(weight, &mut combos));
+        combos.into_iter().for_each(|c| self.push_combo(c));
+        Ok(())
+    }
+
+    /// Adds a combo to the range, replacing the weight if it is already in the range
+    pub fn push_combo(&mut self, combo: Combo) {
+        match self.index.get(&combo.key()) {
+            Some(i) => self.combos[*i].weight = combo.weight,
+            None => {
+                self.index.insert(combo.key(), self.combos.len());
+                self.combos.push(combo);
+            }
+        }
+    }
+
+    /// Returns the combos in the range
+    pub fn combos(&self) -> &[Combo] {
+        self.combos.as_slice()
+    }
+
+    /// Returns the number of combos in the range
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    /// Tests if the range is empty
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Returns the sum of the weights of every combo, i.e. the weighted number of combos
+    pub fn total_weight(&self) -> f64 {
+        self.combos.iter().map(|c| c.weight).sum()
+    }
+
+    /// Removes every combo containing any of the cards, e.g. cards on the board or in our hand
+    pub fn remove_blocked(&mut self, cards: &[Card]) {
+        let blocked = CardSet::from_cards(cards);
+        self.combos.retain(|c| !blocked.contains(&c.cards[0]) && !blocked.contains(&c.cards[1]));
+        self.index = self.combos.iter().enumerate().map(|(i, c)| (c.key(), i)).collect();
+    }
+}
@@ -1,10 +1,16 @@
 use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
 use std::slice::Iter;
+use std::str::FromStr;
+
+use super::{InvalidOrdinal, ParseError};
 
 use self::Rank::*;
 
-/// This enumeration holds the ranks in a standard deck of cards.
-#[derive(Copy, Clone, Hash, Eq, PartialEq, PartialOrd, Debug)]
+/// This enumeration holds the ranks in a standard deck of cards, plus the Joker which ranks above
+/// all of them. The Joker is not one of the standard ranks.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
 pub enum Rank {
     Two,
     Three,
@@ -19,6 +25,13 @@ pub enum Rank {
     Queen,
     King,
     Ace,
+    Joker,
+}
+
+impl PartialOrd for Rank {
+    fn partial_cmp(&self, other: &Rank) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl Ord for Rank {
@@ -35,10 +48,47 @@ impl Ord for Rank {
 }
 
 
+impl fmt::Display for Rank {
+    /// Writes the char of the rank, e.g. "A" for Ace
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl FromStr for Rank {
+    type Err = ParseError;
+
+    /// Parses a rank from its char, e.g. "A" for Ace
+    fn from_str(s: &str) -> Result<Rank, ParseError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Rank::from_char(ch),
+            _ => Err(ParseError::WrongLength { input: s.to_string(), position: 0, expected: 1, found: s.chars().count() }),
+        }
+    }
+}
+
+impl TryFrom<char> for Rank {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Rank, ParseError> {
+        Rank::from_char(ch)
+    }
+}
+
+impl TryFrom<u8> for Rank {
+    type Error = ParseError;
+
+    /// Converts an ASCII byte into a rank in the same way as a char, e.g. b'K' for King
+    fn try_from(byte: u8) -> Result<Rank, ParseError> {
+        Rank::from_char(byte as char)
+    }
+}
+
 impl Rank {
     /// Returns an iterator through the standard ranks
     pub fn iterator() -> Iter<'static, Rank> {
-        Rank::ranks().into_iter()
+        Rank::ranks().iter()
     }
 
     /// Returns an ordinal for the rank.
@@ -57,30 +107,35 @@ impl Rank {
             Queen => 10,
             King => 11,
             Ace => 12,
+            Joker => 13,
         }
     }
 
-    /// A comparator that treats an Ace as a 1
-    pub fn cmp_ace_low(&self, other: &Rank) -> Ordering {
-        // Fudge the ordinal so that Ace can be treated as a 0
-        let mut ord1 = self.ordinal() + 1;
-        let mut ord2 = other.ordinal() + 1;
+    /// Returns an ordinal for the rank where an Ace is treated as a 1, i.e. it is the lowest rank.
+    pub fn ace_low_ordinal(&self) -> usize {
         if *self == Ace {
-            ord1 = 0;
-        }
-        if *other == Ace {
-            ord2 = 0;
+            0
+        } else {
+            self.ordinal() + 1
         }
-        if ord1 < ord2 {
-            return Ordering::Less;
-        } else if ord1 > ord2 {
-            return Ordering::Greater;
+    }
+
+    /// A comparator that treats an Ace as a 1
+    pub fn cmp_ace_low(&self, other: &Rank) -> Ordering {
+        self.ace_low_ordinal().cmp(&other.ace_low_ordinal())
+    }
+
+    /// Returns the rank of an ordinal produced by `ordinal()`, where the Joker is 13
+    pub fn from_ordinal(ordinal: usize) -> Result<Rank, InvalidOrdinal> {
+        match ordinal {
+            13 => Ok(Joker),
+            _ => Rank::ranks().get(ordinal).copied().ok_or(InvalidOrdinal { ordinal }),
         }
-        Ordering::Equal
     }
 
-    /// Returns a Rank represented by a char
-    pub fn from_char(ch: char) -> Result<Rank, &'static str> {
+    /// Returns a Rank represented by a char. As there is only one char, an error reports it at
+    /// position 0.
+    pub fn from_char(ch: char) -> Result<Rank, ParseError> {
         let rank = match ch {
             '2' => Two,
             '3' => Three,
@@ -95,7 +150,8 @@ impl Rank {
             'Q' => Queen,
             'K' => King,
             'A' | '1' => Ace,
-            _ => return Err("Invalid rank")
+            'X' => Joker,
+            _ => return Err(ParseError::InvalidRank { input: ch.to_string(), position: 0, found: ch })
         };
         Ok(rank)
     }
@@ -116,6 +172,7 @@ impl Rank {
             Queen => 'Q',
             King => 'K',
             Ace => 'A',
+            Joker => 'X',
         }
     }
 
@@ -135,9 +192,35 @@ impl Rank {
             Queen => "Queen",
             King => "King",
             Ace => "Ace",
+            Joker => "Joker",
+        }
+    }
+
+    /// Turns a Rank into a plural string, e.g. "Sixes"
+    pub fn to_plural_str(&self) -> &'static str {
+        match *self {
+            Two => "Twos",
+            Three => "Threes",
+            Four => "Fours",
+            Five => "Fives",
+            Six => "Sixes",
+            Seven => "Sevens",
+            Eight => "Eights",
+            Nine => "Nines",
+            Ten => "Tens",
+            Jack => "Jacks",
+            Queen => "Queens",
+            King => "Kings",
+            Ace => "Aces",
+            Joker => "Jokers",
         }
     }
 
+    /// Tests if the rank is a Joker
+    pub fn is_joker(&self) -> bool {
+        *self == Joker
+    }
+
     /// Gets the standard card ranks
     pub fn ranks() -> &'static [Rank] {
         static RANKS: [Rank; 13] = [
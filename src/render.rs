@@ -0,0 +1,180 @@
+use rand::{RngCore, SeedableRng};
+
+use super::*;
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_BLUE: &str = "\x1b[34m";
+
+/// The lines of the back of a card in a box
+const BACK: [&str; 5] = ["+-----+", "|#####|", "|#####|", "|#####|", "+-----+"];
+
+/// The colors suits are drawn in with ANSI escape codes. Black suits are left in the terminal's
+/// own color so they show on dark backgrounds.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ColorScheme {
+    /// Hearts and Diamonds are red, Spades and Clubs are black
+    TwoColor,
+    /// Hearts are red, Diamonds blue, Clubs green and Spades black, so no two suits look alike
+    FourColor,
+}
+
+impl ColorScheme {
+    fn ansi_code(&self, suit: Suit) -> Option<&'static str> {
+        match (self, suit) {
+            (_, Suit::Hearts) | (ColorScheme::TwoColor, Suit::Diamonds) => Some(ANSI_RED),
+            (ColorScheme::FourColor, Suit::Diamonds) => Some(ANSI_BLUE),
+            (ColorScheme::FourColor, Suit::Clubs) => Some(ANSI_GREEN),
+            _ => None,
+        }
+    }
+}
+
+/// Renders cards for a terminal, either as short strings such as "A♠ K♥" or as ASCII-art boxes.
+/// Cards may be colored with ANSI escape codes and drawn face down.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Cards, Renderer};
+/// let renderer = Renderer { colors: None, ..Renderer::default() };
+/// let hand = hand!("AS", "TH");
+/// assert_eq!(renderer.cards(hand.cards()), "A♠ 10♥");
+/// assert_eq!(renderer.card_boxes(hand.cards()), concat!(
+///     "+-----+ +-----+\n",
+///     "|A    | |10   |\n",
+///     "|  ♠  | |  ♥  |\n",
+///     "|    A| |   10|\n",
+///     "+-----+ +-----+"));
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Renderer {
+    /// The colors to draw suits in, or `None` for no escape codes
+    pub colors: Option<ColorScheme>,
+    /// Draws suits as unicode symbols, e.g. '♠', rather than letters, e.g. 'S'
+    pub unicode_suits: bool,
+}
+
+impl Default for Renderer {
+    /// Creates a renderer with two colors and unicode suits
+    fn default() -> Self {
+        Renderer { colors: Some(ColorScheme::TwoColor), unicode_suits: true }
+    }
+}
+
+impl Renderer {
+    /// Creates a renderer without colors or unicode, for logs and plain terminals
+    pub fn plain() -> Renderer {
+        Renderer { colors: None, unicode_suits: false }
+    }
+
+    /// Wraps the text in the color of the suit
+    fn paint(&self, text: &str, suit: Suit) -> String {
+        match self.colors.and_then(|c| c.ansi_code(suit)) {
+            Some(code) => format!("{}{}{}", code, text, ANSI_RESET),
+            None => text.to_string(),
+        }
+    }
+
+    fn rank_label(card: &Card) -> String {
+        match card.rank {
+            Rank::Ten => "10".to_string(),
+            Rank::Joker => "JK".to_string(),
+            r => r.to_char().to_string(),
+        }
+    }
+
+    fn suit_symbol(&self, card: &Card) -> char {
+        if card.is_joker() {
+            '*'
+        } else if self.unicode_suits {
+            card.suit.to_unicode()
+        } else {
+            card.suit.to_char()
+        }
+    }
+
+    /// Returns the card as a short string, e.g. "A♠" or "10♥", colored by its suit
+    pub fn card(&self, card: &Card) -> String {
+        self.paint(&format!("{}{}", Renderer::rank_label(card), self.suit_symbol(card)), card.suit)
+    }
+
+    /// Returns the short string of a face down card
+    pub fn face_down(&self) -> String {
+        "##".to_string()
+    }
+
+    /// Returns the cards as short strings separated by spaces
+    pub fn cards(&self, cards: &[Card]) -> String {
+        cards.iter().map(|c| self.card(c)).collect::<Vec<String>>().join(" ")
+    }
+
+    /// Returns the lines of the box of a card
+    fn box_lines(&self, card: &Card) -> Vec<String> {
+        let rank = Renderer::rank_label(card);
+        let lines = [
+            "+-----+".to_string(),
+            format!("|{:<5}|", rank),
+            format!("|  {}  |", self.suit_symbol(card)),
+            format!("|{:>5}|", rank),
+            "+-----+".to_string(),
+        ];
+        lines.iter().map(|l| self.paint(l, card.suit)).collect()
+    }
+
+    /// Places the boxes side by side, separated by a space. No boxes are drawn as an empty string.
+    fn join_boxes(boxes: &[Vec<String>]) -> String {
+        if boxes.is_empty() {
+            return String::new();
+        }
+        (0..BACK.len())
+            .map(|i| boxes.iter().map(|b| b[i].as_str()).collect::<Vec<&str>>().join(" "))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Returns the card drawn as an ASCII-art box of five lines
+    pub fn card_box(&self, card: &Card) -> String {
+        Renderer::join_boxes(&[self.box_lines(card)])
+    }
+
+    /// Returns the back of a card drawn as an ASCII-art box of five lines
+    pub fn face_down_box(&self) -> String {
+        BACK.join("\n")
+    }
+
+    /// Returns the cards drawn as ASCII-art boxes side by side
+    pub fn card_boxes(&self, cards: &[Card]) -> String {
+        let boxes: Vec<Vec<String>> = cards.iter().map(|c| self.box_lines(c)).collect();
+        Renderer::join_boxes(&boxes)
+    }
+
+    /// Returns the cards drawn as ASCII-art boxes side by side, with only the cards where `face_up`
+    /// is true showing their face, e.g. to show a player's hole cards to everyone else
+    pub fn card_boxes_face_up_if<F>(&self, cards: &[Card], face_up: F) -> String
+        where F: Fn(usize, &Card) -> bool {
+        let back: Vec<String> = BACK.iter().map(|l| l.to_string()).collect();
+        let boxes: Vec<Vec<String>> = cards.iter().enumerate()
+            .map(|(i, c)| if face_up(i, c) { self.box_lines(c) } else { back.clone() })
+            .collect();
+        Renderer::join_boxes(&boxes)
+    }
+
+    /// Returns the deck as a short string of its undealt and dealt counts, with the top card face
+    /// down, e.g. "## 47 undealt, 5 dealt"
+    pub fn deck<R: RngCore + SeedableRng>(&self, deck: &Deck<R>) -> String {
+        let top = if deck.undealt_count() > 0 { self.face_down() } else { "--".to_string() };
+        format!("{} {} undealt, {} dealt", top, deck.undealt_count(), deck.dealt_count())
+    }
+
+    /// Returns the deck drawn as a face down ASCII-art box with its number of undealt cards below,
+    /// or an empty box if every card has been dealt
+    pub fn deck_box<R: RngCore + SeedableRng>(&self, deck: &Deck<R>) -> String {
+        let lines = if deck.undealt_count() > 0 { BACK } else { ["+-----+", "|     |", "|     |", "|     |", "+-----+"] };
+        format!("{}\n{:^7}", lines.join("\n"), deck.undealt_count())
+    }
+}
@@ -0,0 +1,88 @@
+//! Implements `Serialize` and `Deserialize` when the `serde` feature is enabled. Cards are held in
+//! their short form, e.g. "AS", ranks and suits as their char, e.g. 'A' and 'S', hands as a
+//! sequence of cards and decks as their undealt and dealt piles.
+
+use rand::{RngCore, SeedableRng};
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+impl Serialize for Rank {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char(self.to_char())
+    }
+}
+
+impl<'de> Deserialize<'de> for Rank {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Rank, D::Error> {
+        Rank::from_char(char::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Suit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char(self.to_char())
+    }
+}
+
+impl<'de> Deserialize<'de> for Suit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Suit, D::Error> {
+        Suit::from_char(char::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Card, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse::<Card>().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Hand {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.cards.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Hand {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Hand, D::Error> {
+        Ok(Hand::from_cards(&Vec::<Card>::deserialize(deserializer)?))
+    }
+}
+
+/// The piles of a `Deck` as they are serialized. The undealt pile runs from the bottom card to
+/// the top card and the dealt pile is in the order the cards were dealt.
+#[derive(Serialize)]
+#[serde(rename = "Deck")]
+struct DeckPiles<'a> {
+    undealt: &'a [Card],
+    dealt: &'a [Card],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Deck")]
+struct OwnedDeckPiles {
+    undealt: Vec<Card>,
+    dealt: Vec<Card>,
+}
+
+impl<R: RngCore + SeedableRng> Serialize for Deck<R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DeckPiles { undealt: self.cards(), dealt: self.dealt_cards() }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Deck {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Deck, D::Error> {
+        let piles = OwnedDeckPiles::deserialize(deserializer)?;
+        Ok(Deck::from_piles(piles.undealt, piles.dealt))
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::VecDeque;
+use std::result::Result;
+
+use rand::{RngCore, SeedableRng};
+
+use super::*;
+
+/// The default proportion of a `Shoe` which is dealt before the cut card comes out
+const DEFAULT_PENETRATION: f64 = 0.75;
+
+/// Decides when a `Shoe` is reshuffled at the end of a round.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ReshufflePolicy {
+    /// Reshuffle at the end of the round in which the cut card came out
+    CutCard,
+    /// Reshuffle at the end of every round
+    EveryRound,
+    /// Only reshuffle when `reshuffle()` is called
+    Never,
+    /// Model a continuous shuffling machine. At the end of every round the dealt cards are
+    /// loaded into the machine and after waiting for the number of further rounds, they are
+    /// inserted at random positions into the undealt cards. The cut card is never reached.
+    Continuous {
+        /// The number of rounds the dealt cards wait before they return to the undealt cards
+        delay: usize,
+    },
+}
+
+/// A `Shoe` holds several decks of cards shuffled together, as used for blackjack or baccarat.
+/// A cut card is placed into the shoe and once it has been dealt, i.e. the penetration has been
+/// reached, the shoe is reshuffled at the end of the round according to the `ReshufflePolicy`.
+/// Alternatively the shoe can model a continuous shuffling machine with
+/// `ReshufflePolicy::Continuous`.
+///
+/// Like a `Deck`, a shoe keeps the cards it has dealt (including burnt cards) until it is
+/// reshuffled. A new shoe is in the default order so call `reshuffle()` before dealing.
+///
+/// As with a `Deck`, the shoe owns the RNG it is shuffled with, which may be given with
+/// `with_rng()`.
+///
+/// # Example
+///
+/// ```
+/// use deckofcards::Shoe;
+/// let mut shoe = Shoe::with_cut_card(6, 234);
+/// shoe.reshuffle();
+/// shoe.burn(1);
+/// loop {
+///     let _ = shoe.deal(4);
+///     // The cut card comes out after 234 cards so the shoe is reshuffled after the round
+///     if shoe.end_round() {
+///         break;
+///     }
+/// }
+/// assert_eq!(shoe.dealt_count(), 0);
+/// ```
+#[derive(Clone)]
+pub struct Shoe<R = DefaultRng> {
+    deck: Deck<R>,
+    decks: usize,
+    /// The number of cards which are dealt before the cut card comes out
+    cut_card: usize,
+    burned: usize,
+    policy: ReshufflePolicy,
+    /// Cards waiting to return to a continuous shuffling machine, one entry per round
+    buffer: VecDeque<Vec<Card>>,
+}
+
+impl<R: RngCore + SeedableRng> Cards for Shoe<R> {
+    fn cards(&self) -> &[Card] {
+        self.deck.cards()
+    }
+
+    fn mut_cards(&mut self) -> &mut [Card] {
+        self.deck.mut_cards()
+    }
+
+    /// Shuffle the cards into a random order with the shoe's RNG
+    fn shuffle(&mut self) {
+        self.deck.shuffle();
+    }
+
+    /// Reseeds the shoe's RNG from the seed and shuffles the undealt cards with it, see
+    /// `Deck::seeded_shuffle()`
+    fn seeded_shuffle(&mut self, seed: u64) {
+        self.deck.seeded_shuffle(seed);
+    }
+}
+
+impl Shoe {
+    /// Creates a `Shoe` of the number of standard decks with the cut card placed so that three
+    /// quarters of the shoe is dealt
+    pub fn new(decks: usize) -> Shoe {
+        Shoe::with_penetration(decks, DEFAULT_PENETRATION)
+    }
+
+    /// Creates a `Shoe` of the number of standard decks with the cut card placed so the
+    /// proportion of cards, from 0 to 1, is dealt before it comes out
+    pub fn with_penetration(decks: usize, penetration: f64) -> Shoe {
+        let count = decks * Card::all_cards().len();
+        Shoe::with_cut_card(decks, (count as f64 * penetration.clamp(0.0, 1.0)).round() as usize)
+    }
+
+    /// Creates a `Shoe` of the number of standard decks with the cut card placed after the number
+    /// of cards
+    pub fn with_cut_card(decks: usize, cut_card: usize) -> Shoe {
+        Shoe::from_cards(decks, 0, cut_card)
+    }
+
+    /// Creates a `Shoe` of the number of standard decks plus the number of Jokers, e.g. two decks
+    /// and four Jokers for Canasta, with the cut card placed so that three quarters of the shoe is
+    /// dealt. The Jokers are taken in turn from `Card::jokers()` as for `Deck::with_jokers()`.
+    pub fn with_jokers(decks: usize, jokers: usize) -> Shoe {
+        let count = decks * Card::all_cards().len() + jokers;
+        Shoe::from_cards(decks, jokers, (count as f64 * DEFAULT_PENETRATION).round() as usize)
+    }
+
+    fn from_cards(decks: usize, jokers: usize, cut_card: usize) -> Shoe {
+        let mut cards: Vec<Card> = Card::all_cards().iter().cycle().take(decks * Card::all_cards().len()).cloned().collect();
+        cards.extend(Card::jokers().iter().cycle().take(jokers));
+        Shoe {
+            deck: Deck::from_cards(&cards),
+            decks,
+            cut_card: cut_card.min(cards.len()),
+            burned: 0,
+            policy: ReshufflePolicy::CutCard,
+            buffer: VecDeque::new(),
+        }
+    }
+}
+
+impl<R: RngCore + SeedableRng> Shoe<R> {
+    /// Returns the shoe with the RNG which is used to shuffle it from now on
+    pub fn with_rng<S: RngCore + SeedableRng>(self, rng: S) -> Shoe<S> {
+        Shoe {
+            deck: self.deck.with_rng(rng),
+            decks: self.decks,
+            cut_card: self.cut_card,
+            burned: self.burned,
+            policy: self.policy,
+            buffer: self.buffer,
+        }
+    }
+
+    /// Returns the number of decks in the `Shoe`
+    pub fn decks(&self) -> usize {
+        self.decks
+    }
+
+    /// Returns the reshuffle policy
+    pub fn policy(&self) -> ReshufflePolicy {
+        self.policy
+    }
+
+    /// Sets the reshuffle policy
+    pub fn set_policy(&mut self, policy: ReshufflePolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns the number of cards which are dealt before the cut card comes out
+    pub fn cut_card(&self) -> usize {
+        self.cut_card
+    }
+
+    /// Places the cut card after the number of cards
+    pub fn set_cut_card(&mut self, cut_card: usize) {
+        self.cut_card = cut_card.min(self.count());
+    }
+
+    /// Returns the number of remaining undealt cards in the `Shoe`
+    pub fn undealt_count(&self) -> usize {
+        self.deck.undealt_count()
+    }
+
+    /// Returns the number of dealt cards in the `Shoe`, including burnt cards
+    pub fn dealt_count(&self) -> usize {
+        self.deck.dealt_count()
+    }
+
+    /// Returns the number of cards burnt since the last reshuffle
+    pub fn burned_count(&self) -> usize {
+        self.burned
+    }
+
+    /// Returns the number of cards waiting to return to a continuous shuffling machine
+    pub fn buffered_count(&self) -> usize {
+        self.buffer.iter().map(|r| r.len()).sum()
+    }
+
+    /// Returns the number of cards, dealt, undealt or buffered, within the `Shoe`
+    pub fn count(&self) -> usize {
+        self.deck.count() + self.buffered_count()
+    }
+
+    /// Returns the collection of dealt cards
+    pub fn dealt_cards(&self) -> &[Card] {
+        self.deck.dealt_cards()
+    }
+
+    /// Returns the proportion of the `Shoe` which has been dealt, from 0 to 1
+    pub fn penetration(&self) -> f64 {
+        if self.count() == 0 {
+            0.0
+        } else {
+            self.dealt_count() as f64 / self.count() as f64
+        }
+    }
+
+    /// Tests if the cut card has come out, i.e. the `Shoe` is due to be reshuffled
+    pub fn is_cut_card_reached(&self) -> bool {
+        match self.policy {
+            ReshufflePolicy::Continuous { .. } => false,
+            _ => self.dealt_count() >= self.cut_card,
+        }
+    }
+
+    /// Deals the card from the undealt pile. If there are no cards left, the function
+    /// will return an error.
+    pub fn deal_one(&mut self) -> Result<Card, DealError> {
+        self.deck.deal_one()
+    }
+
+    /// Deals a card chosen at random with the shoe's RNG from anywhere in the undealt pile. If
+    /// there are no cards left, the function will return an error.
+    pub fn deal_random(&mut self) -> Result<Card, DealError> {
+        self.deck.deal_random()
+    }
+
+    /// Deals one or more card from the undealt pile and returns them as an array.
+    pub fn deal(&mut self, numcards: usize) -> Vec<Card> {
+        self.deck.deal(numcards)
+    }
+
+    /// Deals one or more card straight to the `Hand`. Returns the number of cards dealt.
+    pub fn deal_to_hand(&mut self, hand: &mut Hand, numcards: usize) -> usize {
+        self.deck.deal_to_hand(hand, numcards)
+    }
+
+    /// Burns one or more cards, i.e. deals them face down out of play. Returns the number of
+    /// cards burnt.
+    pub fn burn(&mut self, numcards: usize) -> usize {
+        let burned = self.deck.deal(numcards).len();
+        self.burned += burned;
+        burned
+    }
+
+    /// Ends a round of play and reshuffles the `Shoe` if the policy says so. Returns true if the
+    /// shoe was reshuffled.
+    pub fn end_round(&mut self) -> bool {
+        let reshuffle = match self.policy {
+            ReshufflePolicy::CutCard => self.is_cut_card_reached(),
+            ReshufflePolicy::EveryRound => true,
+            ReshufflePolicy::Never => false,
+            ReshufflePolicy::Continuous { delay } => {
+                self.load_machine(delay);
+                false
+            }
+        };
+        if reshuffle {
+            self.reshuffle();
+        }
+        reshuffle
+    }
+
+    /// Loads the dealt cards into the continuous shuffling machine and inserts the cards which
+    /// have waited long enough at random positions in the undealt cards
+    fn load_machine(&mut self, delay: usize) {
+        self.buffer.push_back(self.deck.take_dealt_cards());
+        self.burned = 0;
+        while self.buffer.len() > delay {
+            for card in self.buffer.pop_front().unwrap() {
+                self.deck.insert_undealt_card_randomly(card);
+            }
+        }
+    }
+
+    /// Returns every dealt, burnt and buffered card to the `Shoe` and shuffles it
+    pub fn reshuffle(&mut self) {
+        self.deck.extend_undealt_cards(self.buffer.drain(..).flatten());
+        self.deck.reset_shuffle();
+        self.burned = 0;
+    }
+}
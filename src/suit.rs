@@ -1,10 +1,15 @@
 use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
 use std::slice::Iter;
+use std::str::FromStr;
+
+use super::{InvalidOrdinal, ParseError};
 
 use self::Suit::*;
 
 /// This enumeration holds the suits in a standard deck of cards.
-#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Debug)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub enum Suit {
     Spades,
     Hearts,
@@ -12,6 +17,12 @@ pub enum Suit {
     Clubs,
 }
 
+impl PartialOrd for Suit {
+    fn partial_cmp(&self, other: &Suit) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Ord for Suit {
     fn cmp(&self, other: &Suit) -> Ordering {
         let o1 = self.ordinal();
@@ -25,10 +36,47 @@ impl Ord for Suit {
     }
 }
 
+impl fmt::Display for Suit {
+    /// Writes the char of the suit, e.g. "S" for Spades
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl FromStr for Suit {
+    type Err = ParseError;
+
+    /// Parses a suit from its char, e.g. "S" for Spades
+    fn from_str(s: &str) -> Result<Suit, ParseError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Suit::from_char(ch),
+            _ => Err(ParseError::WrongLength { input: s.to_string(), position: 0, expected: 1, found: s.chars().count() }),
+        }
+    }
+}
+
+impl TryFrom<char> for Suit {
+    type Error = ParseError;
+
+    fn try_from(ch: char) -> Result<Suit, ParseError> {
+        Suit::from_char(ch)
+    }
+}
+
+impl TryFrom<u8> for Suit {
+    type Error = ParseError;
+
+    /// Converts an ASCII byte into a suit in the same way as a char, e.g. b'H' for Hearts
+    fn try_from(byte: u8) -> Result<Suit, ParseError> {
+        Suit::from_char(byte as char)
+    }
+}
+
 impl Suit {
     /// Returns an iterator through the standard suits
     pub fn iterator() -> Iter<'static, Suit> {
-        Suit::suits().into_iter()
+        Suit::suits().iter()
     }
 
     /// Returns an ordinal for the suit
@@ -41,14 +89,20 @@ impl Suit {
         }
     }
 
-    /// Returns a Suit for the character, e.g. Hearts for 'H'
-    pub fn from_char(ch: char) -> Result<Suit, &'static str> {
+    /// Returns the suit of an ordinal produced by `ordinal()`
+    pub fn from_ordinal(ordinal: usize) -> Result<Suit, InvalidOrdinal> {
+        Suit::suits().get(ordinal).copied().ok_or(InvalidOrdinal { ordinal })
+    }
+
+    /// Returns a Suit for the character, e.g. Hearts for 'H'. As there is only one char, an error
+    /// reports it at position 0.
+    pub fn from_char(ch: char) -> Result<Suit, ParseError> {
         match ch {
             'S' => Ok(Spades),
             'H' => Ok(Hearts),
             'D' => Ok(Diamonds),
             'C' => Ok(Clubs),
-            _ => Err("Invalid suit")
+            _ => Err(ParseError::InvalidSuit { input: ch.to_string(), position: 0, found: ch })
         }
     }
 
@@ -62,14 +116,14 @@ impl Suit {
         }
     }
 
-    /// Returns a Suit for the unicode character
-    pub fn from_unicode(ch: char) -> Result<Suit, &'static str> {
+    /// Returns a Suit for the unicode character. An error reports the char at position 0.
+    pub fn from_unicode(ch: char) -> Result<Suit, ParseError> {
         match ch {
             '♠' => Ok(Spades),
             '♥' => Ok(Hearts),
             '♦' => Ok(Diamonds),
             '♣' => Ok(Clubs),
-            _ => Err("Invalid suit")
+            _ => Err(ParseError::InvalidSuit { input: ch.to_string(), position: 0, found: ch })
         }
     }
 
@@ -83,6 +137,16 @@ impl Suit {
         }
     }
 
+    /// Tests if the suit is red, i.e. Hearts or Diamonds
+    pub fn is_red(&self) -> bool {
+        matches!(*self, Hearts | Diamonds)
+    }
+
+    /// Tests if the suit is black, i.e. Spades or Clubs
+    pub fn is_black(&self) -> bool {
+        !self.is_red()
+    }
+
     /// Returns a string name of the suit
     pub fn to_str(&self) -> &'static str {
         match *self {
@@ -1,6 +1,10 @@
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::slice::Iter;
+use std::str::FromStr;
+
+use rand::SeedableRng;
+use rand_pcg::Pcg32;
 
 use super::*;
 
@@ -139,6 +143,7 @@ fn card_equality() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn card_from_str() {
     assert_eq!(Card::from_str("TC").unwrap(),
                Card::new(Rank::Ten, Suit::Clubs));
@@ -154,6 +159,7 @@ fn card_from_str() {
     assert!(Card::from_str("AA").is_err());
     assert!(Card::from_str("DD").is_err());
     assert!(Card::from_str("").is_err());
+    assert_eq!(Card::from_str("10h"), <Card as FromStr>::from_str("10h"));
 }
 
 #[test]
@@ -449,3 +455,1087 @@ fn hand_cards_of_rank() {
     let cards = h.cards_of_rank(Rank::King);
     assert_eq!(cards.len(), 0);
 }
+
+#[test]
+fn poker_evaluate_five_categories() {
+    assert_eq!(evaluate_five(hand!("AS", "KS", "QS", "JS", "TS").cards()).unwrap().category, HandCategory::StraightFlush);
+    assert_eq!(evaluate_five(hand!("9C", "9D", "9H", "9S", "2D").cards()).unwrap().category, HandCategory::FourOfAKind);
+    assert_eq!(evaluate_five(hand!("AS", "AS", "AS", "AS", "KD").cards()).unwrap_err(), EvalError::DuplicateCard);
+    assert_eq!(evaluate_five(hand!("3C", "3D", "3H", "KS", "KD").cards()).unwrap().category, HandCategory::FullHouse);
+    assert_eq!(evaluate_five(hand!("2H", "7H", "9H", "JH", "AH").cards()).unwrap().category, HandCategory::Flush);
+    assert_eq!(evaluate_five(hand!("5C", "6D", "7H", "8S", "9D").cards()).unwrap().category, HandCategory::Straight);
+    assert_eq!(evaluate_five(hand!("QC", "QD", "QH", "8S", "9D").cards()).unwrap().category, HandCategory::ThreeOfAKind);
+    assert_eq!(evaluate_five(hand!("KS", "KD", "5H", "5C", "AS").cards()).unwrap().category, HandCategory::TwoPair);
+    assert_eq!(evaluate_five(hand!("KS", "KD", "5H", "4C", "AS").cards()).unwrap().category, HandCategory::OnePair);
+    assert_eq!(evaluate_five(hand!("KS", "JD", "5H", "4C", "AS").cards()).unwrap().category, HandCategory::HighCard);
+    assert_eq!(evaluate_five(hand!("KS", "JD", "5H", "4C").cards()).unwrap_err(), EvalError::WrongHandSize { min: 5, max: 5, found: 4 });
+    assert_eq!(evaluate_hand(&hand!("QC", "QD", "QH", "8S", "9D")).unwrap().category, HandCategory::ThreeOfAKind);
+    assert_eq!(evaluate_hand(&Deck::from_cards(&hand!("2H", "7H", "9H", "JH", "AH").cards)).unwrap().category, HandCategory::Flush);
+}
+
+#[test]
+fn poker_evaluate_five_compare() {
+    // The wheel is the lowest straight
+    let wheel = evaluate_five(hand!("AS", "2D", "3H", "4C", "5S").cards()).unwrap();
+    assert_eq!(wheel.category, HandCategory::Straight);
+    assert_eq!(wheel.ranks, vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]);
+    assert!(wheel < evaluate_five(hand!("2D", "3H", "4C", "5S", "6S").cards()).unwrap());
+    // Kickers decide between the same pairs
+    assert!(evaluate_five(hand!("KS", "KD", "5H", "5C", "AS").cards()).unwrap() > evaluate_five(hand!("KH", "KC", "5D", "5S", "QS").cards()).unwrap());
+    assert_eq!(evaluate_five(hand!("KS", "KD", "5H", "5C", "AS").cards()).unwrap(), evaluate_five(hand!("KH", "KC", "5D", "5S", "AD").cards()).unwrap());
+    // Full house is decided by the three of a kind first
+    assert!(evaluate_five(hand!("3C", "3D", "3H", "2S", "2D").cards()).unwrap() > evaluate_five(hand!("2C", "2H", "2S", "AS", "AD").cards()).unwrap());
+    // Any flush beats any straight
+    assert!(evaluate_five(hand!("2H", "3H", "4H", "5H", "7H").cards()).unwrap() > evaluate_five(hand!("AC", "KD", "QH", "JS", "TD").cards()).unwrap());
+}
+
+#[test]
+fn poker_evaluate_best() {
+    // Hold'em, hole cards plus a board making a wheel with a pair on the board
+    let hole = hand!("AH", "2C");
+    let board = hand!("3D", "4S", "5H", "KD", "KC");
+    let best = evaluate_best(combine_hands!(&hole, &board).cards()).unwrap();
+    assert_eq!(best.rank.category, HandCategory::Straight);
+    assert_eq!(best.rank.ranks[0], Rank::Five);
+    let mut cards = Hand::from_cards(&best.cards);
+    cards.sort_descending_rank_suit();
+    assert_eq!(cards.to_string(), "AH,5H,4S,3D,2C");
+
+    // Six cards
+    let best = evaluate_best(hand!("9S", "9D", "9H", "4C", "4D", "4S").cards()).unwrap();
+    assert_eq!(best.rank, HandRank::new(HandCategory::FullHouse, vec![Rank::Nine, Rank::Four]));
+    assert_eq!(best.cards.len(), 5);
+
+    assert!(evaluate_best(hand!("9S", "9D", "9H", "4C").cards()).is_err());
+    assert_eq!(evaluate_best(hand!("AS", "AS", "AS", "AS", "AS", "KD").cards()).unwrap_err(), EvalError::DuplicateCard);
+    assert_eq!(evaluate_best(hand!("9S", "9D", "9H", "4C", "4D", "4S", "2C", "3C").cards()).unwrap_err(), EvalError::WrongHandSize { min: 5, max: 7, found: 8 });
+}
+
+#[test]
+fn lookup_matches_evaluate_best() {
+    // Compare the lookup evaluator to the simple evaluator over many random hands of 5 to 7 cards
+    let mut deck = deck!();
+    for seed in 0..3000 {
+        deck.reset();
+        deck.seeded_shuffle(seed);
+        let cards = deck.deal(5 + (seed as usize % 3));
+        let value = evaluate_fast(&cards);
+        let best = evaluate_best(&cards).unwrap();
+        assert_eq!(value.to_hand_rank(), best.rank, "hand {}", Hand::from_cards(&cards));
+    }
+}
+
+#[test]
+fn lookup_evaluate_fast() {
+    assert_eq!(evaluate_fast(hand!("AS", "2S", "3S", "4S", "5S", "KD", "KC").cards()).category(), HandCategory::StraightFlush);
+    assert_eq!(evaluate_fast(hand!("AS", "2D", "3S", "4S", "5S", "KS", "KC").cards()).category(), HandCategory::Flush);
+    assert_eq!(evaluate_fast(hand!("AS", "AD", "AC", "KS", "KD", "KC", "2C").cards()).to_hand_rank().ranks, vec![Rank::Ace, Rank::King]);
+    assert!(evaluate_fast(hand!("AS", "2D", "3S", "4S", "5C").cards()) < evaluate_fast(hand!("6S", "2D", "3S", "4S", "5C").cards()));
+    assert!(evaluate_fast(hand!("AS", "AD", "QS", "QD", "2C", "2D", "KH").cards()) > evaluate_fast(hand!("AS", "AD", "QS", "QD", "2C", "2D", "JH").cards()));
+    assert_eq!(card_mask(&[card!("2S"), card!("3S")]), 0b11);
+}
+
+#[test]
+fn lookup_equivalence_class() {
+    assert_eq!(equivalence_class(hand!("AS", "KS", "QS", "JS", "TS").cards()).unwrap(), 1);
+    assert_eq!(equivalence_class(hand!("AH", "KH", "QH", "JH", "TH").cards()).unwrap(), 1);
+    assert_eq!(equivalence_class(hand!("5D", "4D", "3D", "2D", "AD").cards()).unwrap(), 10);
+    assert_eq!(equivalence_class(hand!("AS", "AD", "AC", "AH", "KS").cards()).unwrap(), 11);
+    assert_eq!(equivalence_class(hand!("7S", "5D", "4C", "3H", "2S").cards()).unwrap(), 7462);
+    assert!(equivalence_class(hand!("KS", "KD", "5H", "5C", "AS").cards()).unwrap() < equivalence_class(hand!("KS", "KD", "5H", "5C", "QS").cards()).unwrap());
+    assert!(equivalence_class(hand!("KS", "KD", "5H", "5C").cards()).is_err());
+    assert_eq!(equivalence_class(hand!("KS", "KS", "5H", "5C", "2D").cards()).unwrap_err(), EvalError::DuplicateCard);
+    assert_eq!(evaluate_fast(hand!("AS", "KD", "QH").cards()).equivalence_class(), None);
+
+    // Every class round trips through its value
+    let mut previous: Option<HandValue> = None;
+    for class in 1..=7462 {
+        let value = HandValue::from_equivalence_class(class).unwrap();
+        assert_eq!(value.equivalence_class(), Some(class));
+        assert!(previous.map_or(true, |p| p > value));
+        previous = Some(value);
+    }
+    assert!(HandValue::from_equivalence_class(0).is_none());
+    assert!(HandValue::from_equivalence_class(7463).is_none());
+}
+
+#[test]
+fn lookup_description() {
+    assert_eq!(evaluate_fast(hand!("KS", "KD", "5H", "5C", "AS").cards()).description(), "Two Pair, Kings and Fives, Ace kicker");
+    assert_eq!(evaluate_fast(hand!("AS", "KS", "QS", "JS", "TS").cards()).description(), "Royal Flush");
+    assert_eq!(evaluate_fast(hand!("5D", "4D", "3D", "2D", "AD").cards()).description(), "Straight Flush, Five high");
+    assert_eq!(evaluate_fast(hand!("6C", "6D", "6H", "6S", "2D").cards()).description(), "Four of a Kind, Sixes, Two kicker");
+    assert_eq!(evaluate_fast(hand!("3C", "3D", "3H", "KS", "KD").cards()).description(), "Full House, Threes over Kings");
+    assert_eq!(evaluate_fast(hand!("2H", "7H", "9H", "JH", "QH").cards()).description(), "Flush, Queen high");
+    assert_eq!(evaluate_fast(hand!("TC", "6D", "7H", "8S", "9D").cards()).description(), "Straight, Ten high");
+    assert_eq!(evaluate_fast(hand!("QC", "QD", "QH", "8S", "9D").cards()).description(), "Three of a Kind, Queens, Nine kicker");
+    assert_eq!(evaluate_fast(hand!("KS", "KD", "5H", "4C", "AS").cards()).description(), "One Pair, Kings, Ace kicker");
+    assert_eq!(evaluate_fast(hand!("KS", "JD", "5H", "4C", "AS").cards()).description(), "High Card, Ace");
+}
+
+#[test]
+fn lowball_eight_or_better() {
+    let best = evaluate_eight_or_better(hand!("AS", "2D", "3H", "4C", "5S", "KD", "KC").cards()).unwrap().unwrap();
+    assert_eq!(best.rank.ranks, vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]);
+    // 8-6-4-2-A loses to 7-6-5-4-3
+    let l1 = evaluate_eight_or_better(hand!("8S", "6D", "4H", "2C", "AS").cards()).unwrap().unwrap();
+    let l2 = evaluate_eight_or_better(hand!("7S", "6D", "5H", "4C", "3S").cards()).unwrap().unwrap();
+    assert!(l2.rank > l1.rank);
+    assert!(best.rank > l2.rank);
+    // No qualifying low
+    assert!(evaluate_eight_or_better(hand!("9S", "6D", "4H", "2C", "AS").cards()).unwrap().is_none());
+    assert!(evaluate_eight_or_better(hand!("8S", "8D", "4H", "2C", "AS", "KS").cards()).unwrap().is_none());
+}
+
+#[test]
+fn omaha_evaluate() {
+    // A single spade in the hole cannot make a flush
+    let hole = hand!("AS", "KD", "7D", "2C");
+    let board = hand!("QS", "JS", "TS", "9S", "3H");
+    let best = evaluate_omaha(hole.cards(), board.cards()).unwrap();
+    assert_eq!(best.rank.category, HandCategory::Straight);
+    assert_eq!(best.rank.ranks[0], Rank::Ace);
+    let used = Hand::from_cards(&best.cards);
+    assert_eq!(used.cards().iter().filter(|c| hole.cards().contains(c)).count(), 2);
+
+    // Four of a kind on the board can only play three of its cards
+    let hole = hand!("3S", "3D", "7D", "8C");
+    let board = hand!("KS", "KD", "KH", "KC", "3H");
+    let best = evaluate_omaha(hole.cards(), board.cards()).unwrap();
+    assert_eq!(best.rank.category, HandCategory::FullHouse);
+
+    assert_eq!(evaluate_omaha(hand!("AS", "KD", "7D").cards(), board.cards()).unwrap_err(), EvalError::WrongHoleSize { min: 4, max: 6, found: 3 });
+    assert_eq!(evaluate_omaha(hole.cards(), hand!("KS", "KD").cards()).unwrap_err(), EvalError::WrongBoardSize { min: 3, max: 5, found: 2 });
+    assert_eq!(evaluate_omaha(hole.cards(), hand!("3S", "KD", "KH").cards()).unwrap_err(), EvalError::DuplicateCard);
+}
+
+#[test]
+fn omaha_evaluate_low() {
+    // Needs two low hole cards and three low board cards
+    let board = hand!("2S", "5D", "7H", "KC", "QH");
+    let low = evaluate_omaha_low(hand!("AS", "3D", "KD", "QC").cards(), board.cards()).unwrap().unwrap();
+    assert_eq!(low.rank.ranks, vec![Rank::Seven, Rank::Five, Rank::Three, Rank::Two, Rank::Ace]);
+    assert!(evaluate_omaha_low(hand!("AS", "KD", "QD", "JC").cards(), board.cards()).unwrap().is_none());
+    // Hole cards which pair the board cannot help
+    assert!(evaluate_omaha_low(hand!("2D", "5C", "KD", "JC", "TC").cards(), board.cards()).unwrap().is_none());
+}
+
+#[test]
+fn lowball_ace_to_five() {
+    // The wheel is the best hand, straights and flushes do not count
+    let wheel = evaluate_ace_to_five(hand!("AS", "2S", "3S", "4S", "5S").cards()).unwrap().rank;
+    assert_eq!(wheel.ranks, vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]);
+    assert!(wheel > evaluate_ace_to_five(hand!("AS", "2D", "3H", "4C", "6S").cards()).unwrap().rank);
+    // Any unpaired hand beats a pair, and a lower pair beats a higher one
+    assert!(evaluate_ace_to_five(hand!("KS", "QD", "JH", "TC", "9S").cards()).unwrap().rank > evaluate_ace_to_five(hand!("AS", "AD", "2H", "3C", "4S").cards()).unwrap().rank);
+    assert!(evaluate_ace_to_five(hand!("2S", "2D", "KH", "QC", "JS").cards()).unwrap().rank > evaluate_ace_to_five(hand!("3S", "3D", "4H", "5C", "6S").cards()).unwrap().rank);
+    // Razz picks the best five of seven
+    let razz = evaluate_ace_to_five(hand!("AS", "AD", "2H", "7C", "6S", "4D", "KH").cards()).unwrap();
+    assert_eq!(razz.rank.ranks, vec![Rank::Seven, Rank::Six, Rank::Four, Rank::Two, Rank::Ace]);
+}
+
+#[test]
+fn lowball_deuce_to_seven() {
+    let best = evaluate_deuce_to_seven(hand!("7S", "5D", "4H", "3C", "2S").cards()).unwrap().rank;
+    // Aces are high and the wheel is not a straight
+    assert!(best > evaluate_deuce_to_seven(hand!("AS", "2D", "3H", "4C", "5S").cards()).unwrap().rank);
+    assert!(evaluate_deuce_to_seven(hand!("AS", "2D", "3H", "4C", "5S").cards()).unwrap().rank > evaluate_deuce_to_seven(hand!("2S", "2D", "3H", "4C", "5S").cards()).unwrap().rank);
+    // Straights and flushes count against the hand
+    assert!(evaluate_deuce_to_seven(hand!("8S", "5D", "4H", "3C", "2S").cards()).unwrap().rank > evaluate_deuce_to_seven(hand!("6S", "5D", "4H", "3C", "2S").cards()).unwrap().rank);
+    assert!(evaluate_deuce_to_seven(hand!("8S", "5D", "4H", "3C", "2S").cards()).unwrap().rank > evaluate_deuce_to_seven(hand!("7S", "5S", "4S", "3S", "2S").cards()).unwrap().rank);
+    assert!(evaluate_deuce_to_seven(hand!("7S", "5D", "4H", "3C").cards()).is_err());
+}
+
+#[test]
+fn lowball_badugi() {
+    let best = evaluate_badugi(hand!("AS", "2H", "3D", "4C").cards()).unwrap();
+    assert_eq!(best.cards.len(), 4);
+    assert!(best.rank > evaluate_badugi(hand!("AS", "2H", "3D", "5C").cards()).unwrap().rank);
+    // A pair or a repeated suit reduces the number of cards played
+    let three = evaluate_badugi(hand!("AS", "AH", "3D", "4C").cards()).unwrap();
+    assert_eq!(three.cards.len(), 3);
+    assert_eq!(three.rank.ranks, vec![Rank::Four, Rank::Three, Rank::Ace]);
+    assert!(evaluate_badugi(hand!("KS", "QH", "JD", "TC").cards()).unwrap().rank > three.rank);
+    let one = evaluate_badugi(hand!("KS", "QS", "5S", "9S").cards()).unwrap();
+    assert_eq!(one.cards, vec![card!("5S")]);
+    assert!(evaluate_badugi(hand!("KS", "QS", "5S").cards()).is_err());
+}
+
+#[test]
+fn equity_exhaustive() {
+    // Aces against kings on the turn, only the two remaining kings win for the kings
+    let players = [hand!("AS", "AD"), hand!("KS", "KD")];
+    let board = hand!("2C", "7H", "9D", "JC");
+    let equity = exhaustive_equity(&players, board.cards(), &[]).unwrap();
+    assert_eq!(equity[0].trials(), 44);
+    assert_eq!(equity[0].wins, 42);
+    assert_eq!(equity[1].wins, 2);
+    assert_eq!(equity[1].losses, 42);
+
+    // A dead king leaves only one out
+    let equity = exhaustive_equity(&players, board.cards(), &[card!("KH")]).unwrap();
+    assert_eq!(equity[1].wins, 1);
+    assert_eq!(equity[1].trials(), 43);
+
+    // Both players play the board
+    let equity = exhaustive_equity(&[hand!("2S", "3D"), hand!("2D", "3S")], hand!("AS", "KH", "QD", "JC", "TS").cards(), &[]).unwrap();
+    assert_eq!(equity[0].ties, 1);
+    assert_eq!(equity[0].equity_percent(), 50.0);
+
+    assert_eq!(exhaustive_equity(&players[..1], board.cards(), &[]).unwrap_err(), EquityError::TooFewPlayers { found: 1 });
+    assert_eq!(exhaustive_equity(&[hand!("AS", "AD"), hand!("AS", "KD")], board.cards(), &[]).unwrap_err(), EquityError::DuplicateCard);
+    assert_eq!(exhaustive_equity(&[hand!("AS", "AD"), hand!("KS")], board.cards(), &[]).unwrap_err(), EquityError::WrongHoleSize { player: 1, found: 1 });
+    // 24 players leave only four cards for the board
+    let mut deck = Deck::new();
+    let players: Vec<Hand> = (0..24).map(|_| Hand::from_cards(&deck.deal(2))).collect();
+    assert_eq!(exhaustive_equity(&players, &[], &[]).unwrap_err(), EquityError::NotEnoughCards { needed: 5, available: 4 });
+    assert_eq!(monte_carlo_equity(&players, &[], &[], 10, 1).unwrap_err(), EquityError::NotEnoughCards { needed: 5, available: 4 });
+}
+
+#[test]
+fn equity_monte_carlo() {
+    let players = [hand!("AS", "AD"), hand!("7C", "2H")];
+    let e1 = monte_carlo_equity(&players, &[], &[], 2000, 1).unwrap();
+    let e2 = monte_carlo_equity(&players, &[], &[], 2000, 1).unwrap();
+    // The same seed reproduces the same results
+    assert_eq!(e1, e2);
+    assert_eq!(e1[0].trials(), 2000);
+    // Aces are close to 88% against seven-deuce
+    assert!(e1[0].equity_percent() > 83.0 && e1[0].equity_percent() < 93.0);
+    assert!((e1[0].equity_percent() + e1[1].equity_percent() - 100.0).abs() < 1e-6);
+}
+
+#[test]
+fn range_parse() {
+    let len = |s: &str| Range::parse(s).unwrap().len();
+    assert_eq!(len("AA"), 6);
+    assert_eq!(len("TT+"), 30);
+    assert_eq!(len("TT-77"), 24);
+    assert_eq!(len("77-TT"), 24);
+    assert_eq!(len("AKs"), 4);
+    assert_eq!(len("AKo"), 12);
+    assert_eq!(len("AK"), 16);
+    assert_eq!(len("KA"), 16);
+    assert_eq!(len("A9s+"), 20);
+    assert_eq!(len("76s+"), 32);
+    assert_eq!(len("A5s-A2s"), 16);
+    assert_eq!(len("76s-43s"), 16);
+    assert_eq!(len("AsKd"), 1);
+    assert_eq!(len("AsKd, KdAs, AKo"), 12);
+    assert_eq!(len(""), 0);
+
+    let range = Range::parse("AsKd, QQ:0.5").unwrap();
+    assert_eq!(range.combos()[0].cards, [card!("AS"), card!("KD")]);
+    assert_eq!(range.combos()[0].to_string(), "ASKD");
+    assert_eq!(range.total_weight(), 1.0 + 3.0);
+
+    // Later items replace the weight of earlier ones
+    let range = Range::parse("AKs, AKs:0.25").unwrap();
+    assert_eq!(range.total_weight(), 1.0);
+
+    assert_eq!(Range::parse("QQ+, AKx").unwrap_err(), RangeError::InvalidSuitedness { item: "AKx".to_string(), found: 'x' });
+    assert!(Range::parse("AAs").is_err());
+    assert_eq!(Range::parse("AK:2").unwrap_err(), RangeError::InvalidWeight { item: "AK:2".to_string() });
+    assert!(Range::parse("AK:x").is_err());
+    assert!(Range::parse("AKs-QQ").is_err());
+    assert!(Range::parse("AKs-Q9s").is_err());
+    assert!(Range::parse("AsAs").is_err());
+    assert!(Range::parse("Z2").is_err());
+
+    // Suitedness may be in either case but exact cards must be rank then suit
+    assert_eq!(Range::parse("AKS").unwrap().len(), 4);
+    assert_eq!(Range::parse("akO").unwrap().len(), 12);
+    assert_eq!(Range::parse("SAKD").unwrap_err(), RangeError::InvalidCards { item: "SAKD".to_string() });
+    assert!(Range::parse("A♠K♦").is_err());
+}
+
+#[test]
+fn range_remove_blocked() {
+    let mut range = Range::parse("AA, KK, AKs").unwrap();
+    range.remove_blocked(&[card!("AS"), card!("KH")]);
+    assert_eq!(range.len(), 3 + 3 + 2);
+    assert!(range.combos().iter().all(|c| !c.is_blocked_by(&[card!("AS"), card!("KH")])));
+
+    // Combos pushed after removing still replace the weight of the same cards
+    range.push_combo(Combo::new(card!("KS"), card!("KD"), 0.5));
+    range.push_combo(Combo::new(card!("AS"), card!("AD"), 0.5));
+    assert_eq!(range.len(), 3 + 3 + 2 + 1);
+    assert_eq!(range.total_weight(), 8.0 - 0.5 + 0.5);
+}
+
+#[test]
+fn card_set_ops() {
+    let mut set = CardSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(card!("AS")));
+    assert!(!set.insert(card!("AS")));
+    assert!(set.insert(card!("2C")));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&card!("AS")));
+    assert!(!set.contains(&card!("AD")));
+    assert!(set.remove(&card!("AS")));
+    assert!(!set.remove(&card!("AS")));
+    assert_eq!(set.len(), 1);
+
+    let a = CardSet::from(&hand!("AS", "KS", "QS"));
+    let b = CardSet::from(&hand!("QS", "JS"));
+    assert_eq!((a | b).len(), 4);
+    assert_eq!(a & b, CardSet::from(&hand!("QS")));
+    assert_eq!(a - b, CardSet::from(&hand!("AS", "KS")));
+    assert_eq!((!a).len(), 49);
+    assert_eq!(!CardSet::all(), CardSet::new());
+    assert_eq!(a.union(&a.complement()), CardSet::all());
+}
+
+#[test]
+fn card_set_conversions() {
+    // Iterates in ordinal order
+    let set = CardSet::from(&hand!("2H", "AS", "3S"));
+    assert_eq!(set.to_hand().to_string(), "3S,AS,2H");
+    assert_eq!(set.to_string(), "3S,AS,2H");
+    assert_eq!(set.iter().count(), 3);
+    assert_eq!(set.iter().collect::<CardSet>(), set);
+    assert_eq!(set.to_deck().count(), 3);
+
+    let mut deck = deck!();
+    assert_eq!(CardSet::from(&deck), CardSet::all());
+    let dealt = deck.deal(5);
+    let undealt = CardSet::from(&deck);
+    assert_eq!(undealt.len(), 47);
+    assert!(dealt.iter().all(|c| !undealt.contains(c)));
+    assert_eq!(CardSet::all().to_hand().cards(), Card::all_cards());
+    assert_eq!(CardSet::from_bits(card_mask(&dealt)), CardSet::from_cards(&dealt));
+    // Only the bits of the 56 card ordinals are kept
+    let full = CardSet::from_bits(u64::MAX);
+    assert_eq!(full.len(), 56);
+    assert_eq!(full.iter().size_hint(), (56, Some(56)));
+    assert_eq!(full.iter().count(), 56);
+}
+
+#[test]
+fn card_jokers() {
+    let red = Card::red_joker();
+    let black = Card::black_joker();
+    assert!(red.is_joker() && black.is_joker());
+    assert!(!card!("AS").is_joker());
+    assert_eq!(red.name(), "Red Joker");
+    assert_eq!(black.name(), "Black Joker");
+    assert_eq!(red.to_str(), "XH");
+    assert_eq!(card!("XH"), red);
+    assert_eq!(card!("XS"), black);
+    assert_eq!(Rank::from_char('X').unwrap(), Rank::Joker);
+    assert!(!Rank::ranks().contains(&Rank::Joker));
+
+    // Jokers have their own ordinals after the standard cards
+    let ordinals: HashSet<usize> = Card::iterator().chain(Card::jokers().iter()).map(|c| c.ordinal()).collect();
+    assert_eq!(ordinals.len(), 56);
+    Card::jokers().iter().for_each(|j| assert_eq!(Card::from_ordinal(j.ordinal()), Some(*j)));
+    assert!(Card::from_ordinal(56).is_none());
+    let mut set = CardSet::new();
+    set.insert(red);
+    assert!(set.contains(&red) && !set.contains(&black));
+    assert_eq!(set.complement(), CardSet::all());
+
+    // Jokers take their colour from their suit but are not of it
+    assert!(!black.is_spades() && !red.is_hearts());
+    assert!(!Card::joker(Suit::Clubs).is_clubs() && !Card::joker(Suit::Diamonds).is_diamonds());
+    assert!(black.is_black() && red.is_red());
+    assert_eq!((black.rank(), black.suit()), (Rank::Joker, None));
+    assert_eq!(card!("QD").suit(), Some(Suit::Diamonds));
+}
+
+#[test]
+fn deck_with_jokers() {
+    let mut d = Deck::with_jokers(2);
+    assert_eq!(d.count(), 54);
+    let cards = d.deal(54);
+    assert_eq!(cards.iter().filter(|c| c.is_joker()).count(), 2);
+    assert!(cards.contains(&Card::red_joker()));
+    assert!(cards.contains(&Card::black_joker()));
+    assert_eq!(Deck::with_jokers(0).count(), 52);
+    let cards = Deck::with_jokers(4).deal(56);
+    assert_eq!(CardSet::from_cards(&cards).len(), 56);
+    // More than four Jokers repeat them
+    let cards = Deck::with_jokers(6).deal(58);
+    assert_eq!(cards.iter().filter(|c| c.is_joker()).count(), 6);
+    assert_eq!(cards.iter().filter(|c| **c == Card::black_joker()).count(), 2);
+    assert!(cards.iter().filter(|c| c.suit() == Some(Suit::Spades)).all(|c| !c.is_joker()));
+    // A Canasta shoe is two decks and four Jokers
+    let shoe = Shoe::with_jokers(2, 4);
+    assert_eq!(shoe.count(), 108);
+    assert_eq!(shoe.cards().iter().filter(|c| c.is_joker()).count(), 4);
+    assert_eq!(shoe.cut_card(), 81);
+    let d = Deck::with_jokers(2);
+    assert_eq!(cards_of_suit(d.cards(), Suit::Spades).len(), 13);
+    assert_eq!(cards_of_suit(d.cards(), Suit::Hearts).len(), 13);
+
+    // Sorting by suit puts the Jokers last
+    let mut h = hand!("XH", "2S", "XS", "AH");
+    h.sort_suit_ascending_rank();
+    assert_eq!(h.to_string(), "2S,AH,XS,XH");
+    h.sort_suit_descending_rank();
+    assert_eq!(h.to_string(), "2S,AH,XS,XH");
+    // Jokers cannot be evaluated until they stand for another card
+    assert_eq!(evaluate_five(hand!("AS", "KS", "QS", "JS", "XS").cards()).unwrap_err(), EvalError::Joker);
+}
+
+#[test]
+fn wild_cards() {
+    let wild = WildCards::one_eyed_jacks();
+    assert!(wild.is_wild(&card!("JS")));
+    assert!(wild.is_wild(&card!("JH")));
+    assert!(!wild.is_wild(&card!("JD")));
+    let mut wild = WildCards::deuces();
+    wild.push_card(Card::red_joker());
+    assert_eq!(wild.count(hand!("2S", "2D", "XH", "XS", "3C").cards()), 3);
+    assert_eq!(wild.natural_cards(hand!("2S", "XS", "3C").cards()), vec![card!("XS"), card!("3C")]);
+    assert!(WildCards::new().count(hand!("2S", "XH").cards()) == 0);
+}
+
+#[test]
+fn wild_evaluate() {
+    let jokers = WildCards::jokers();
+    let value = |h: Hand, wild: &WildCards| evaluate_wild(h.cards(), wild).unwrap();
+    // A joker completes the straight flush
+    let v = value(hand!("9S", "TS", "JS", "QS", "XH", "2D", "3C"), &jokers);
+    assert_eq!(v.description(), "Straight Flush, King high");
+    // Four aces and a joker is five of a kind, which beats a royal flush
+    let v = value(hand!("AS", "AH", "AD", "AC", "XS"), &jokers);
+    assert_eq!(v.description(), "Five of a Kind, Aces");
+    assert!(v > value(hand!("AS", "KS", "QS", "JS", "XS"), &jokers));
+    assert_eq!(v.equivalence_class(), None);
+    // Deuces wild
+    let v = value(hand!("2S", "2D", "7C", "7H", "KD"), &WildCards::deuces());
+    assert_eq!(v.category(), HandCategory::FourOfAKind);
+    let v = value(hand!("2S", "2D", "7C", "7H", "7D", "KD", "KS"), &WildCards::deuces());
+    assert_eq!(v.description(), "Five of a Kind, Sevens");
+    let v = value(hand!("2S", "TH", "JH", "QH", "AH", "3C", "3D"), &WildCards::deuces());
+    assert_eq!(v.description(), "Royal Flush");
+    // All wild
+    let v = value(hand!("2S", "2D", "2C", "2H", "XS"), &WildCards::deuces().union(&jokers));
+    assert_eq!(v.description(), "Five of a Kind, Aces");
+    // Jokers which are not wild cannot be evaluated
+    assert!(evaluate_wild(hand!("2S", "2D", "7C", "7H", "XD").cards(), &WildCards::deuces()).is_err());
+    assert!(evaluate_wild(hand!("2S", "2D", "7C", "7H").cards(), &jokers).is_err());
+}
+
+#[test]
+fn shoe_count() {
+    let mut shoe = Shoe::new(6);
+    assert_eq!(shoe.decks(), 6);
+    assert_eq!(shoe.count(), 312);
+    assert_eq!(shoe.cut_card(), 234);
+    shoe.reshuffle();
+    // Six of every card
+    let mut hand = Hand::new();
+    assert_eq!(shoe.deal_to_hand(&mut hand, 400), 312);
+    assert_eq!(hand.cards_of_rank(Rank::Ace).len(), 24);
+    assert_eq!(hand.cards().iter().filter(|c| **c == card!("QH")).count(), 6);
+    assert!(shoe.deal_one().is_err());
+    assert_eq!(shoe.penetration(), 1.0);
+
+    assert_eq!(Shoe::with_penetration(8, 0.5).cut_card(), 208);
+    assert_eq!(Shoe::with_cut_card(1, 100).cut_card(), 52);
+}
+
+#[test]
+fn shoe_cut_card() {
+    let mut shoe = Shoe::with_cut_card(2, 60);
+    shoe.reshuffle();
+    assert_eq!(shoe.burn(1), 1);
+    assert_eq!(shoe.burned_count(), 1);
+    let _ = shoe.deal(50);
+    assert!(!shoe.is_cut_card_reached());
+    assert!(!shoe.end_round());
+    let _ = shoe.deal(9);
+    assert!(shoe.is_cut_card_reached());
+    assert_eq!(shoe.dealt_count(), 60);
+    assert!((shoe.penetration() - 60.0 / 104.0).abs() < 1e-9);
+    // The round finishes before the shoe is reshuffled
+    let _ = shoe.deal(5);
+    assert!(shoe.end_round());
+    assert_eq!(shoe.undealt_count(), 104);
+    assert_eq!(shoe.burned_count(), 0);
+}
+
+#[test]
+fn shoe_reshuffle_policy() {
+    let mut shoe = Shoe::new(1);
+    assert_eq!(shoe.policy(), ReshufflePolicy::CutCard);
+    shoe.set_policy(ReshufflePolicy::EveryRound);
+    let _ = shoe.deal(5);
+    assert!(shoe.end_round());
+    assert_eq!(shoe.dealt_count(), 0);
+    shoe.set_policy(ReshufflePolicy::Never);
+    let _ = shoe.deal(50);
+    assert!(!shoe.end_round());
+    assert_eq!(shoe.dealt_count(), 50);
+}
+
+#[test]
+fn shoe_continuous_shuffler() {
+    let mut shoe = Shoe::new(1).with_rng(Pcg32::seed_from_u64(1));
+    shoe.set_policy(ReshufflePolicy::Continuous { delay: 1 });
+    shoe.reshuffle();
+
+    // The first round's cards wait a round in the machine
+    let round1 = shoe.deal(10);
+    assert!(!shoe.end_round());
+    assert_eq!(shoe.buffered_count(), 10);
+    assert_eq!(shoe.undealt_count(), 42);
+    assert_eq!(shoe.count(), 52);
+
+    let _ = shoe.deal(40);
+    assert!(!shoe.is_cut_card_reached());
+    assert!(!shoe.end_round());
+    assert_eq!(shoe.buffered_count(), 40);
+    assert_eq!(shoe.undealt_count(), 12);
+    assert!(round1.iter().all(|c| shoe.cards().contains(c)));
+
+    // The machine never runs out however much is dealt
+    for _ in 0..100 {
+        assert_eq!(shoe.deal(5).len(), 5);
+        shoe.end_round();
+        assert_eq!(shoe.count(), 52);
+    }
+    // The last round is still in the machine
+    let dealt = shoe.deal(52);
+    assert_eq!(shoe.buffered_count(), 5);
+    assert_eq!(dealt.len(), 47);
+
+    shoe.reshuffle();
+    assert_eq!(shoe.undealt_count(), 52);
+    assert_eq!(CardSet::from_cards(shoe.cards()), CardSet::all());
+}
+
+#[test]
+fn cards_riffle_shuffle() {
+    let mut rng = Pcg32::seed_from_u64(7);
+    let mut d = deck!();
+    d.riffle_shuffle(&mut rng);
+    assert_eq!(CardSet::from(&d), CardSet::all());
+    assert_ne!(d.cards(), Card::all_cards());
+    // A single riffle leaves at most two rising sequences of the original order
+    let mut positions = [0; 52];
+    d.cards().iter().enumerate().for_each(|(i, c)| positions[c.ordinal()] = i);
+    let descents = (0..51).filter(|i| positions[i + 1] < positions[*i]).count();
+    assert!(descents <= 1);
+}
+
+#[test]
+fn cards_overhand_and_strip_cut_shuffle() {
+    let mut rng = Pcg32::seed_from_u64(7);
+    let mut h = Hand::from_cards(Card::all_cards());
+    h.overhand_shuffle(&mut rng);
+    assert_eq!(CardSet::from(&h), CardSet::all());
+    assert_ne!(h.cards(), Card::all_cards());
+
+    // Stripping into two packets swaps the packets but keeps the order within them
+    let cards = hand!("2S", "3S", "4S", "5S", "6S", "7S");
+    let mut h = cards.clone();
+    h.strip_cut_shuffle(2, &mut rng);
+    let rotations: Vec<Vec<Card>> = (1..6).map(|i| [&cards.cards()[i..], &cards.cards()[..i]].concat()).collect();
+    assert!(rotations.iter().any(|r| r.as_slice() == h.cards()));
+    let s = h.to_string();
+    h.strip_cut_shuffle(1, &mut rng);
+    assert_eq!(h.to_string(), s);
+    let mut empty = Hand::new();
+    empty.strip_cut_shuffle(3, &mut rng);
+    empty.riffle_shuffle(&mut rng);
+    empty.overhand_shuffle(&mut rng);
+}
+
+#[test]
+fn cards_faro_shuffles() {
+    // The top of the hand is the last card
+    let mut h = hand!("2S", "3S", "4S", "5S", "6S", "7S");
+    h.out_faro();
+    assert_eq!(h.to_string(), "2S,5S,3S,6S,4S,7S");
+    let mut h = hand!("2S", "3S", "4S", "5S", "6S", "7S");
+    h.in_faro();
+    assert_eq!(h.to_string(), "5S,2S,6S,3S,7S,4S");
+    let mut h = hand!("2S", "3S", "4S", "5S", "6S");
+    h.out_faro();
+    assert_eq!(h.to_string(), "4S,2S,5S,3S,6S");
+
+    let mut d = Deck::new();
+    let original = d.cards().to_vec();
+    assert_eq!(d.out_faro_restore_count(), 8);
+    for _ in 0..8 {
+        d.out_faro();
+    }
+    assert_eq!(d.cards(), original.as_slice());
+    assert_eq!(out_faro_restore_count(0), 1);
+    assert_eq!(out_faro_restore_count(10), 6);
+    let mut d = Deck::new();
+    for _ in 0..52 {
+        d.in_faro();
+    }
+    assert_eq!(d.cards(), original.as_slice());
+}
+
+#[test]
+fn cards_cut() {
+    let mut h = hand!("2S", "3S", "4S", "5S", "6S");
+    h.cut(2);
+    assert_eq!(h.to_string(), "5S,6S,2S,3S,4S");
+    h.cut(8);
+    assert_eq!(h.to_string(), "2S,3S,4S,5S,6S");
+    let mut empty = Hand::new();
+    empty.cut(3);
+}
+
+#[test]
+fn analysis_rising_sequences() {
+    let original = Deck::new();
+    assert_eq!(rising_sequences(original.cards(), original.cards()), 1);
+    let mut reversed = original.cards().to_vec();
+    reversed.reverse();
+    assert_eq!(rising_sequences(original.cards(), &reversed), 52);
+    let mut d = Deck::new();
+    d.cut(10);
+    assert_eq!(rising_sequences(original.cards(), d.cards()), 2);
+}
+
+#[test]
+fn analysis_shuffle_quality() {
+    let mut rng = Pcg32::seed_from_u64(3);
+    let random = analyze_shuffle(&Deck::new(), 2000, |d| d.knuth_shuffle(&mut rng)).unwrap();
+    assert!((random.mean_rising_sequences - random.expected_rising_sequences).abs() < 0.5);
+    let chi_per_df = random.position_chi_square / random.position_degrees_of_freedom as f64;
+    assert!(chi_per_df > 0.9 && chi_per_df < 1.1);
+    assert!((random.adjacent_pair_survival - random.expected_adjacent_pair_survival).abs() < 0.005);
+    assert!(random.total_variation_distance < 0.15);
+
+    // Seven riffles are famously close to random, four are not
+    let riffles = |count| {
+        let mut rng = Pcg32::seed_from_u64(3);
+        analyze_shuffle(&Deck::new(), 2000, |d| (0..count).for_each(|_| d.riffle_shuffle(&mut rng))).unwrap()
+    };
+    assert!(riffles(4).total_variation_distance > 0.6);
+    assert!(riffles(7).total_variation_distance < 0.4);
+
+    let unshuffled = analyze_shuffle(&Deck::new(), 10, |_| {}).unwrap();
+    assert_eq!(unshuffled.adjacent_pair_survival, 1.0);
+    assert!(unshuffled.total_variation_distance > 0.999);
+    assert_eq!(analyze_shuffle(&Deck::new(), 10, |d| { d.deal_one().unwrap(); }).unwrap_err(), AnalysisError::CardsChanged);
+    assert!(analyze_shuffle(&Deck::from_cards(&[Card::red_joker(), Card::red_joker()]), 10, |_| {}).is_err());
+}
+
+#[test]
+fn analysis_shuffle_with_owned_rng() {
+    // The deck's own RNG carries on between trials rather than repeating the same shuffle
+    let deck = Deck::new().with_rng(rand_chacha::ChaCha20Rng::seed_from_u64(1));
+    let report = analyze_shuffle(&deck, 2000, |d| d.shuffle()).unwrap();
+    assert!((report.mean_rising_sequences - report.expected_rising_sequences).abs() < 0.5);
+    let chi_per_df = report.position_chi_square / report.position_degrees_of_freedom as f64;
+    assert!(chi_per_df > 0.9 && chi_per_df < 1.1);
+    assert!(report.total_variation_distance < 0.15);
+}
+
+#[test]
+fn permutation_index_round_trip() {
+    let d = Deck::new();
+    assert_eq!(d.order_index().unwrap(), [0; PERMUTATION_INDEX_BYTES]);
+    assert_eq!(d.order_id().unwrap(), "0".repeat(38));
+
+    // The last order is every card reversed, 52! - 1
+    let mut reversed = Card::all_cards().to_vec();
+    reversed.reverse();
+    let last = permutation_index(&reversed).unwrap();
+    assert_eq!(permutation_index_to_id(&last), "cjrHIoqaOOLcl5qRL2N8Z0FHkFzVHn904g2ohz");
+    assert_eq!(permutation_from_index(&last).unwrap(), reversed);
+    assert_eq!(permutation_from_index(&[0xff; PERMUTATION_INDEX_BYTES]).unwrap_err(), PermutationError::IndexOutOfRange);
+
+    let mut rng = Pcg32::seed_from_u64(16);
+    let mut d = Deck::new();
+    d.knuth_shuffle(&mut rng);
+    d.deal(5);
+    let id = d.order_id().unwrap();
+    let mut copy = Deck::from_order_id(&id).unwrap();
+    copy.deal(5);
+    assert_eq!(copy.cards(), d.cards());
+    assert_eq!(copy.dealt_cards(), d.dealt_cards());
+}
+
+#[test]
+fn permutation_invalid() {
+    assert!(permutation_index(hand!("AS", "KS").cards()).is_err());
+    let mut duplicated = Card::all_cards().to_vec();
+    duplicated[0] = duplicated[1];
+    assert!(permutation_index(&duplicated).is_err());
+    assert!(Deck::with_jokers(1).order_id().is_err());
+    assert_eq!(permutation_index_from_id("0").unwrap_err(), PermutationError::WrongIdLength { found: 1 });
+    assert_eq!(permutation_index_from_id(&"!".repeat(38)).unwrap_err(), PermutationError::InvalidIdChar { found: '!' });
+    assert!(permutation_index_from_id(&"z".repeat(38)).is_err());
+}
+
+#[cfg(feature = "fair")]
+#[test]
+fn fair_deck_known_answer() {
+    let seed = ServerSeed::from_bytes(std::array::from_fn(|i| i as u8));
+    assert_eq!(seed.commitment(), "630dcd2966c4336691125448bbb25b4ff412a49c732db2c8abc1b8581bd710dd");
+    assert_eq!(ServerSeed::from_hex(&seed.to_hex()).unwrap(), seed);
+    let mut deck = fair_deck(&seed, "client", 7);
+    assert_eq!(Hand::from_cards(&deck.deal(5)).to_string(), "7S,QH,TH,8D,2D");
+    assert!(verify_fair_deck(&seed.commitment(), &seed, "client", 7, &deck).is_ok());
+}
+
+#[cfg(feature = "fair")]
+#[test]
+fn fair_deck_verification_fails() {
+    let seed = ServerSeed::new();
+    let commitment = seed.commitment();
+    let deck = fair_deck(&seed, "client", 1);
+    assert_eq!(verify_fair_deck(&commitment, &ServerSeed::new(), "client", 1, &deck).unwrap_err(), FairError::CommitmentMismatch);
+    assert_eq!(verify_fair_deck(&commitment, &seed, "client", 2, &deck).unwrap_err(), FairError::OrderMismatch);
+    assert!(verify_fair_deck(&commitment, &seed, "other", 1, &deck).is_err());
+    assert!(verify_fair_deck(&commitment.to_uppercase(), &seed, "client", 1, &deck).is_ok());
+    assert!(ServerSeed::from_hex("00").is_err());
+    assert!(ServerSeed::from_hex(&"g".repeat(64)).is_err());
+}
+
+#[test]
+fn deck_with_rng() {
+    use rand_chacha::ChaCha20Rng;
+    let mut d1 = Deck::new().with_rng(ChaCha20Rng::seed_from_u64(18));
+    let mut d2 = Deck::with_jokers(2).with_rng(ChaCha20Rng::seed_from_u64(18));
+    d1.deal(3);
+    d1.reset_shuffle();
+    d2.shuffle();
+    assert_eq!(d2.count(), 54);
+    assert_ne!(d1.cards(), Card::all_cards());
+
+    // The same seed gives the same order every time
+    let mut d3 = Deck::new().with_rng(ChaCha20Rng::seed_from_u64(18));
+    d3.reset_shuffle();
+    assert_eq!(d1.cards(), d3.cards());
+    d1.shuffle();
+    d3.shuffle();
+    assert_eq!(d1.cards(), d3.cards());
+    assert_eq!(CardSet::from(&d1), CardSet::all());
+
+    // A seeded shuffle reseeds the deck's own RNG and later shuffles carry on from the seed
+    let mut d4 = Deck::new().with_rng(ChaCha20Rng::seed_from_u64(5));
+    d4.shuffle();
+    d3.seeded_shuffle(5);
+    d1.knuth_shuffle(&mut ChaCha20Rng::seed_from_u64(5));
+    assert_eq!(d1.cards(), d3.cards());
+    let mut d5 = Deck::new().with_rng(ChaCha20Rng::seed_from_u64(6));
+    d5.seeded_shuffle(5);
+    assert_eq!(d5.cards(), d4.cards());
+    d4.shuffle();
+    d5.shuffle();
+    assert_eq!(d5.cards(), d4.cards());
+
+    // The default RNG is seeded as a ChaCha20 generator, while a hand still shuffles with a Pcg32
+    let mut d6 = Deck::new();
+    d6.seeded_shuffle(7);
+    let mut d7 = Deck::new();
+    d7.knuth_shuffle(&mut ChaCha20Rng::seed_from_u64(7));
+    assert_eq!(d6.cards(), d7.cards());
+    let mut h1 = Hand::from_cards(Card::all_cards());
+    h1.seeded_shuffle(7);
+    let mut h2 = Hand::from_cards(Card::all_cards());
+    h2.knuth_shuffle(&mut Pcg32::seed_from_u64(7));
+    assert_eq!(h1.cards(), h2.cards());
+}
+
+#[test]
+fn deck_deal_random() {
+    use rand_chacha::ChaCha20Rng;
+    let mut d1 = Deck::new().with_rng(ChaCha20Rng::seed_from_u64(3));
+    let mut d2 = Deck::new().with_rng(ChaCha20Rng::seed_from_u64(3));
+    let cards: Vec<Card> = (0..52).map(|_| d1.deal_random().unwrap()).collect();
+    assert_eq!(CardSet::from_cards(&cards), CardSet::all());
+    assert_ne!(cards[..5], d2.cards()[47..]);
+    assert_eq!(d1.dealt_cards(), cards.as_slice());
+    assert_eq!(d1.deal_random(), Err(DealError::NoCardsLeft));
+    assert_eq!((0..5).map(|_| d2.deal_random().unwrap()).collect::<Vec<Card>>(), cards[..5]);
+    assert_eq!(d2.undealt_count(), 47);
+
+    let mut rng = ChaCha20Rng::seed_from_u64(3);
+    let mut hand = hand!("AS", "KS", "QS");
+    let card = hand.remove_random(&mut rng).unwrap();
+    assert_eq!(hand.len(), 2);
+    assert!(!hand.cards().contains(&card));
+    hand.clear();
+    assert_eq!(hand.remove_random(&mut rng), None);
+}
+
+#[test]
+fn shoe_with_rng() {
+    let mut s1 = Shoe::new(2).with_rng(rand_chacha::ChaCha20Rng::seed_from_u64(18));
+    let mut s2 = Shoe::new(2).with_rng(rand_chacha::ChaCha20Rng::seed_from_u64(18));
+    s1.reshuffle();
+    s2.reshuffle();
+    assert_eq!(s1.deal(104), s2.deal(104));
+    s1.set_policy(ReshufflePolicy::EveryRound);
+    assert!(s1.end_round());
+    assert_eq!(s1.undealt_count(), 104);
+}
+
+/// A 256-bit safe prime which keeps the mental poker tests quick
+#[cfg(feature = "mental-poker")]
+fn mental_poker_prime() -> BigUint {
+    BigUint::parse_bytes(b"a52e4cc6717c8c47eb957431347d967f7b20a939bea1b3c6416599f8ba14f3ab", 16).unwrap()
+}
+
+#[cfg(feature = "mental-poker")]
+#[test]
+fn mental_poker_deal() {
+    use rand_chacha::ChaCha20Rng;
+    let prime = mental_poker_prime();
+    let mut rng = ChaCha20Rng::seed_from_u64(19);
+    let mut parties: Vec<SraParty> = (0..3).map(|_| SraParty::new(&prime, &mut rng)).collect();
+    let mut deck = EncryptedDeck::new(&prime);
+    parties.iter().for_each(|p| p.shuffle_deck(&mut deck, &mut rng));
+    parties.iter_mut().for_each(|p| p.lock_deck(&mut deck, &mut rng));
+
+    // Every card is revealed exactly once with every party's key for it
+    let revealed: Vec<Card> = (0..deck.len())
+        .map(|i| {
+            let keys: Vec<&SraKey> = parties.iter().map(|p| p.card_key(i).unwrap()).collect();
+            deck.reveal_card(i, &keys).unwrap()
+        })
+        .collect();
+    assert_eq!(CardSet::from_cards(&revealed), CardSet::all());
+    assert_ne!(revealed, Card::all_cards());
+
+    // Without every key the card stays hidden
+    let keys: Vec<&SraKey> = parties[1..].iter().map(|p| p.card_key(0).unwrap()).collect();
+    assert!(deck.reveal_card(0, &keys).is_err());
+    assert_eq!(deck.reveal_card(52, &keys).unwrap_err(), RevealError::NoCard { index: 52 });
+}
+
+#[cfg(feature = "mental-poker")]
+#[test]
+fn mental_poker_keys_commute() {
+    use rand_chacha::ChaCha20Rng;
+    let prime = sra_prime();
+    let mut rng = ChaCha20Rng::seed_from_u64(19);
+    let (a, b) = (SraKey::generate(&prime, &mut rng), SraKey::generate(&prime, &mut rng));
+    let value = BigUint::from(1234u32);
+    let ab = b.encrypt(&a.encrypt(&value, &prime), &prime);
+    assert_eq!(ab, a.encrypt(&b.encrypt(&value, &prime), &prime));
+    assert_eq!(b.decrypt(&a.decrypt(&ab, &prime), &prime), value);
+
+    let deck = EncryptedDeck::from_cards(&[Card::red_joker()], &prime);
+    assert_eq!(deck.reveal_card(0, &[]).unwrap(), Card::red_joker());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_cards_and_hands() {
+    assert_eq!(serde_json::to_string(&card!("AS")).unwrap(), "\"AS\"");
+    assert_eq!(serde_json::to_string(&Rank::Ten).unwrap(), "\"T\"");
+    assert_eq!(serde_json::to_string(&Suit::Clubs).unwrap(), "\"C\"");
+    assert_eq!(serde_json::from_str::<Card>("\"XH\"").unwrap(), Card::red_joker());
+    assert_eq!(serde_json::from_str::<Rank>("\"Q\"").unwrap(), Rank::Queen);
+    assert_eq!(serde_json::from_str::<Suit>("\"D\"").unwrap(), Suit::Diamonds);
+    assert!(serde_json::from_str::<Card>("\"ZZ\"").is_err());
+    assert!(serde_json::from_str::<Suit>("\"Z\"").is_err());
+
+    let hand = hand!("AS", "KD", "2C");
+    let json = serde_json::to_string(&hand).unwrap();
+    assert_eq!(json, "[\"AS\",\"KD\",\"2C\"]");
+    assert_eq!(serde_json::from_str::<Hand>(&json).unwrap().cards(), hand.cards());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_deck() {
+    let mut deck = Deck::from_cards(hand!("2S", "3S", "4S", "5S").cards());
+    deck.deal(2);
+    let json = serde_json::to_string(&deck).unwrap();
+    assert_eq!(json, "{\"undealt\":[\"2S\",\"3S\"],\"dealt\":[\"5S\",\"4S\"]}");
+    let mut copy: Deck = serde_json::from_str(&json).unwrap();
+    assert_eq!(copy.cards(), deck.cards());
+    assert_eq!(copy.dealt_cards(), deck.dealt_cards());
+    copy.reset();
+    assert_eq!(copy.cards(), hand!("2S", "3S", "4S", "5S").cards());
+    assert!(serde_json::from_str::<Deck>("{\"undealt\":[]}").is_err());
+}
+
+#[test]
+fn error_parse_card() {
+    assert_eq!("ADC".parse::<Card>().unwrap_err(), ParseError::WrongLength { input: "ADC".to_string(), position: 0, expected: 2, found: 3 });
+    assert_eq!("AZ".parse::<Card>().unwrap_err(), ParseError::InvalidSuit { input: "AZ".to_string(), position: 1, found: 'Z' });
+    assert_eq!("DD".parse::<Card>().unwrap_err(), ParseError::InvalidRank { input: "DD".to_string(), position: 1, found: 'D' });
+    let err = "ZS".parse::<Card>().unwrap_err();
+    assert_eq!(err.input(), "ZS");
+    assert_eq!(err.position(), 0);
+    assert_eq!(err.to_string(), "Invalid rank 'Z' at position 0 in \"ZS\"");
+    assert_eq!(Rank::from_char('Z').unwrap_err().to_string(), "Invalid rank 'Z' at position 0 in \"Z\"");
+    assert_eq!(Suit::from_char('Z').unwrap_err(), ParseError::InvalidSuit { input: "Z".to_string(), position: 0, found: 'Z' });
+
+    // The errors work with ?
+    fn parse(s: &str) -> Result<Card, Box<dyn std::error::Error>> {
+        Ok(s.parse::<Card>()?)
+    }
+    assert!(parse("AS").is_ok());
+    assert_eq!(parse("").unwrap_err().to_string(), "Expected 2 chars but found 0 at position 0 in \"\"");
+}
+
+#[test]
+fn error_deal() {
+    let mut d = Deck::from_cards(&[card!("AS")]);
+    assert!(d.deal_one().is_ok());
+    assert_eq!(d.deal_one().unwrap_err(), DealError::NoCardsLeft);
+    assert_eq!(DealError::NoCardsLeft.to_string(), "No cards left");
+}
+
+#[test]
+fn from_str_traits() {
+    assert_eq!("AS".parse::<Card>().unwrap(), card!("AS"));
+    assert_eq!("Q".parse::<Rank>().unwrap(), Rank::Queen);
+    assert_eq!("H".parse::<Suit>().unwrap(), Suit::Hearts);
+    assert!("QQ".parse::<Rank>().is_err());
+    assert!("".parse::<Suit>().is_err());
+    assert_eq!(Rank::Ten.to_string(), "T");
+    assert_eq!(Suit::Clubs.to_string(), "C");
+
+    // A hand round-trips through Display
+    let hand: Hand = "AS,KD".parse().unwrap();
+    assert_eq!(hand.cards(), hand!("AS", "KD").cards());
+    assert_eq!(hand.to_string().parse::<Hand>().unwrap().cards(), hand.cards());
+    assert_eq!(" AS , KD ".parse::<Hand>().unwrap().cards(), hand.cards());
+    assert!("".parse::<Hand>().unwrap().is_empty());
+    assert_eq!("AS,KZ".parse::<Hand>().err(), Some(ParseError::InvalidSuit { input: "AS,KZ".to_string(), position: 4, found: 'Z' }));
+    assert!("AS,,KD".parse::<Hand>().is_err());
+
+    let mut deck: Deck = "2S,3S,4S".parse().unwrap();
+    assert_eq!(deck.deal_one().unwrap(), card!("4S"));
+}
+
+#[test]
+fn try_from_traits() {
+    use std::convert::TryFrom;
+    assert_eq!(Rank::try_from('K').unwrap(), Rank::King);
+    assert_eq!(Suit::try_from('D').unwrap(), Suit::Diamonds);
+    assert!(Rank::try_from('Z').is_err());
+    assert_eq!(Rank::try_from(b'K').unwrap(), Rank::King);
+    assert_eq!(Suit::try_from(b'D').unwrap(), Suit::Diamonds);
+    assert_eq!(Rank::try_from(b'Z').unwrap_err(), Rank::try_from('Z').unwrap_err());
+    assert_eq!(Suit::try_from(3u8).unwrap_err(), ParseError::InvalidSuit { input: "\u{3}".to_string(), position: 0, found: '\u{3}' });
+    assert!(Rank::ranks().iter().all(|r| Rank::from_ordinal(r.ordinal()).unwrap() == *r));
+    assert!(Suit::suits().iter().all(|s| Suit::from_ordinal(s.ordinal()).unwrap() == *s));
+    assert_eq!(Rank::from_ordinal(13).unwrap(), Rank::Joker);
+    assert_eq!(Rank::from_ordinal(14).unwrap_err(), InvalidOrdinal { ordinal: 14 });
+    assert_eq!(Suit::from_ordinal(4).unwrap_err().to_string(), "Invalid ordinal 4");
+}
+
+#[test]
+fn parse_lenient_cards() {
+    let ace_spades = Card::new(Rank::Ace, Suit::Spades);
+    let ten_hearts = Card::new(Rank::Ten, Suit::Hearts);
+    for s in ["AS", "As", "as", "SA", "sa", "A♠", "♠A", " AS ", "Ace of Spades", "ace  of spade", "1S"] {
+        assert_eq!(s.parse::<Card>().unwrap(), ace_spades, "{}", s);
+    }
+    for s in ["TH", "10H", "10h", "♥10", "ten of hearts", "10 of Hearts"] {
+        assert_eq!(s.parse::<Card>().unwrap(), ten_hearts, "{}", s);
+    }
+    assert_eq!("Red Joker".parse::<Card>().unwrap(), Card::red_joker());
+    assert_eq!("xs".parse::<Card>().unwrap(), Card::black_joker());
+
+    assert_eq!(" Ace of Stars".parse::<Card>().unwrap_err(), ParseError::InvalidName { input: " Ace of Stars".to_string(), position: 1 });
+    assert_eq!(" AZ".parse::<Card>().unwrap_err().position(), 2);
+    assert_eq!(" 10".parse::<Card>().unwrap_err(), ParseError::WrongLength { input: " 10".to_string(), position: 1, expected: 3, found: 2 });
+    assert!("AsKd".parse::<Card>().is_err());
+}
+
+#[test]
+fn parse_lenient_hands() {
+    let expected = hand!("AS", "KD", "QH");
+    for s in ["AS,KD,QH", "As Kd Qh", "AsKdQh", "As, Kd, Qh", "A♠K♦Q♥", "♠A ♦K ♥Q", "Ace of Spades, King of Diamonds, queen of hearts", "As Kd,Qh"] {
+        assert_eq!(s.parse::<Hand>().unwrap().cards(), expected.cards(), "{}", s);
+    }
+    assert_eq!("10h10s".parse::<Hand>().unwrap().to_string(), "TH,TS");
+
+    let err = "AsKdQz".parse::<Hand>().err().unwrap();
+    assert_eq!(err, ParseError::InvalidSuit { input: "AsKdQz".to_string(), position: 5, found: 'z' });
+    let err = "AsKdQ".parse::<Hand>().err().unwrap();
+    assert_eq!(err, ParseError::WrongLength { input: "AsKdQ".to_string(), position: 4, expected: 2, found: 1 });
+    let err = "As,10,Kd".parse::<Hand>().err().unwrap();
+    assert_eq!(err, ParseError::WrongLength { input: "As,10,Kd".to_string(), position: 3, expected: 3, found: 2 });
+    assert_eq!("As,,Kd".parse::<Hand>().err().map(|e| e.position()), Some(3));
+    let err = "Kd, Ace of Stars".parse::<Hand>().err().unwrap();
+    assert_eq!(err, ParseError::InvalidName { input: "Kd, Ace of Stars".to_string(), position: 4 });
+    assert_eq!(err.to_string(), "Invalid card name at position 4 in \"Kd, Ace of Stars\"");
+}
+
+#[test]
+fn glyph_cards() {
+    assert_eq!(card!("AS").to_unicode(), '\u{1F0A1}');
+    assert_eq!(card!("TH").to_unicode(), '\u{1F0BA}');
+    assert_eq!(card!("JD").to_unicode(), '\u{1F0CB}');
+    assert_eq!(card!("QD").to_unicode(), '\u{1F0CD}');
+    assert_eq!(card!("KC").to_unicode(), '\u{1F0DE}');
+    assert_eq!(card!("2C").to_unicode(), '\u{1F0D2}');
+    assert_eq!(Card::red_joker().to_unicode(), '\u{1F0BF}');
+    assert_eq!(Card::black_joker().to_unicode(), '\u{1F0CF}');
+    assert_eq!(Card::joker(Suit::Diamonds).to_unicode(), '\u{1F0BF}');
+
+    // Every card round-trips
+    assert!(Card::all_cards().iter().all(|c| Card::from_unicode(c.to_unicode()) == Some(*c)));
+    assert_eq!(Card::from_unicode('\u{1F0BF}'), Some(Card::red_joker()));
+    assert_eq!(Card::from_unicode('\u{1F0CF}'), Some(Card::black_joker()));
+    assert_eq!(Card::from_unicode('\u{1F0AC}'), None);
+    assert_eq!(Card::from_unicode('A'), None);
+    assert_eq!(Card::from_unicode('\u{1F0E1}'), None);
+}
+
+#[test]
+fn glyph_others() {
+    assert_eq!(CardGlyph::from_char('\u{1F0A0}'), Some(CardGlyph::Back));
+    assert_eq!(CardGlyph::from_char('\u{1F0B0}'), None);
+    assert_eq!(CardGlyph::from_char('\u{1F0DF}'), Some(CardGlyph::WhiteJoker));
+    assert_eq!(CardGlyph::from_char('\u{1F0DC}'), Some(CardGlyph::Knight(Suit::Clubs)));
+    assert_eq!(CardGlyph::Knight(Suit::Hearts).to_char(), '\u{1F0BC}');
+    assert_eq!(CardGlyph::WhiteJoker.to_string(), "\u{1F0DF}");
+
+    let hand = hand!("AS", "KD");
+    assert_eq!(hand.to_unicode_string(), "\u{1F0A1} \u{1F0CE}");
+    assert_eq!(hand.to_unicode_string().parse::<Hand>().unwrap().cards(), hand.cards());
+    assert_eq!(Hand::new().to_unicode_string(), "");
+}
+
+#[test]
+fn render_colors() {
+    let renderer = Renderer::default();
+    assert_eq!(renderer.card(&card!("AS")), "A♠");
+    assert_eq!(renderer.card(&card!("TH")), "\x1b[31m10♥\x1b[0m");
+    assert_eq!(renderer.card(&card!("2D")), "\x1b[31m2♦\x1b[0m");
+    assert_eq!(renderer.card(&Card::red_joker()), "\x1b[31mJK*\x1b[0m");
+
+    let four = Renderer { colors: Some(ColorScheme::FourColor), ..Renderer::default() };
+    assert_eq!(four.cards(&[card!("KS"), card!("QH"), card!("JD"), card!("9C")]),
+               "K♠ \x1b[31mQ♥\x1b[0m \x1b[34mJ♦\x1b[0m \x1b[32m9♣\x1b[0m");
+
+    let plain = Renderer::plain();
+    assert_eq!(plain.cards(&[card!("AS"), card!("TD")]), "AS 10D");
+    assert_eq!(plain.face_down(), "##");
+}
+
+#[test]
+fn render_boxes() {
+    let plain = Renderer::plain();
+    assert_eq!(plain.card_box(&card!("QC")), "+-----+\n|Q    |\n|  C  |\n|    Q|\n+-----+");
+    assert_eq!(plain.face_down_box(), "+-----+\n|#####|\n|#####|\n|#####|\n+-----+");
+    assert_eq!(plain.card_boxes_face_up_if(&[card!("AS"), card!("KD")], |i, _| i == 1),
+               "+-----+ +-----+\n|#####| |K    |\n|#####| |  D  |\n|#####| |    K|\n+-----+ +-----+");
+    assert_eq!(plain.card_boxes(&[]), "");
+    assert_eq!(plain.card_boxes_face_up_if(&[], |_, _| true), "");
+
+    // Each line of a colored box is colored on its own
+    let line = Renderer::default().card_box(&card!("3H")).lines().nth(1).unwrap().to_string();
+    assert_eq!(line, "\x1b[31m|3    |\x1b[0m");
+
+    let mut deck = Deck::new();
+    deck.deal(5);
+    assert_eq!(plain.deck(&deck), "## 47 undealt, 5 dealt");
+    assert!(plain.deck_box(&deck).ends_with("+-----+\n  47   "));
+    deck.deal(47);
+    assert_eq!(plain.deck(&deck), "-- 0 undealt, 52 dealt");
+    assert!(plain.deck_box(&deck).starts_with("+-----+\n|     |"));
+}
@@ -0,0 +1,162 @@
+use super::*;
+
+/// The set of cards which are wild in a game, e.g. Jokers, deuces or one-eyed Jacks. A wild card
+/// may stand for any other card.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Card, WildCards};
+/// let wild = WildCards::jokers().union(&WildCards::deuces());
+/// assert!(wild.is_wild(&Card::red_joker()));
+/// assert!(wild.is_wild(&card!("2D")));
+/// assert!(!wild.is_wild(&card!("3D")));
+/// # }
+/// ```
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Default, Debug)]
+pub struct WildCards {
+    cards: CardSet,
+}
+
+impl WildCards {
+    /// Creates a set of wild cards where nothing is wild
+    pub fn new() -> WildCards {
+        WildCards::default()
+    }
+
+    /// Creates a set of wild cards from the cards
+    pub fn from_cards(cards: &[Card]) -> WildCards {
+        WildCards { cards: CardSet::from_cards(cards) }
+    }
+
+    /// Creates a set where every Joker is wild
+    pub fn jokers() -> WildCards {
+        WildCards::from_cards(Card::jokers())
+    }
+
+    /// Creates a set where every Two is wild
+    pub fn deuces() -> WildCards {
+        let mut wild = WildCards::new();
+        wild.push_rank(Rank::Two);
+        wild
+    }
+
+    /// Creates a set where the one-eyed Jacks, the Jack of Spades and Jack of Hearts, are wild
+    pub fn one_eyed_jacks() -> WildCards {
+        WildCards::from_cards(&[Card::new(Rank::Jack, Suit::Spades), Card::new(Rank::Jack, Suit::Hearts)])
+    }
+
+    /// Makes the card wild
+    pub fn push_card(&mut self, card: Card) {
+        self.cards.insert(card);
+    }
+
+    /// Makes every card of the rank wild
+    pub fn push_rank(&mut self, rank: Rank) {
+        Suit::iterator().for_each(|s| self.push_card(Card::new(rank, *s)));
+    }
+
+    /// Returns a set where cards in either set are wild
+    pub fn union(&self, other: &WildCards) -> WildCards {
+        WildCards { cards: self.cards | other.cards }
+    }
+
+    /// Tests if the card is wild
+    pub fn is_wild(&self, card: &Card) -> bool {
+        self.cards.contains(card)
+    }
+
+    /// Returns the number of wild cards in the slice
+    pub fn count(&self, cards: &[Card]) -> usize {
+        cards.iter().filter(|c| self.is_wild(c)).count()
+    }
+
+    /// Returns the cards of the slice which are not wild
+    pub fn natural_cards(&self, cards: &[Card]) -> Vec<Card> {
+        cards.iter().filter(|c| !self.is_wild(c)).cloned().collect()
+    }
+}
+
+/// Evaluates the best poker hand from five to seven cards where the wild cards may stand for any
+/// card not already held, or make five of a kind, which beats a straight flush. Five or more wild
+/// cards make five Aces.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate deckofcards;
+/// # fn main() {
+/// use deckofcards::{Card, Cards, HandCategory, WildCards, evaluate_wild};
+/// let mut hand = hand!("AS", "AD", "KC", "7H");
+/// hand.push_card(Card::black_joker());
+/// let value = evaluate_wild(hand.cards(), &WildCards::jokers()).unwrap();
+/// assert_eq!(value.category(), HandCategory::ThreeOfAKind);
+/// # }
+/// ```
+pub fn evaluate_wild(cards: &[Card], wild: &WildCards) -> Result<HandValue, EvalError> {
+    poker::check_hand_size(cards, 5, 7)?;
+    let natural = wild.natural_cards(cards);
+    poker::check_no_jokers(&natural)?;
+    let wild_count = cards.len() - natural.len();
+    let natural = CardSet::from_cards(&natural);
+
+    // Five of a kind beats everything else so take the highest rank the wild cards can make it in
+    let mut counts = [0usize; 13];
+    natural.iter().for_each(|c| counts[c.rank.ordinal()] += 1);
+    if let Some(rank) = (0..13).rev().find(|r| counts[*r] + wild_count >= 5) {
+        return Ok(HandValue::new(HandCategory::FiveOfAKind, 1 << rank, 0));
+    }
+
+    let mut best = evaluate_mask(natural.bits());
+    for substitutes in wild_choices(&natural, wild_count) {
+        best = best.max(evaluate_mask(natural.bits() | card_mask(&substitutes)));
+    }
+    Ok(best)
+}
+
+/// Returns the cards which are not already held
+fn missing<I: Iterator<Item = Card>>(natural: &CardSet, cards: I) -> Vec<Card> {
+    cards.filter(|c| !natural.contains(c)).collect()
+}
+
+/// Returns the choices of cards for the wild cards to stand for which can make the best hand, as
+/// decided by the ranks and suits already held. Splitting the wild cards between ranks never
+/// beats putting them all on one rank, so the choices are every wild card on the same rank, the
+/// highest missing cards of a suit, and the missing cards of each straight and straight flush.
+fn wild_choices(natural: &CardSet, wild_count: usize) -> Vec<Vec<Card>> {
+    let mut choices = Vec::new();
+    if wild_count == 0 {
+        return choices;
+    }
+    for rank in Rank::iterator() {
+        let mut cards = missing(natural, Suit::iterator().map(|s| Card::new(*rank, *s)));
+        cards.truncate(wild_count);
+        choices.push(cards);
+    }
+    for suit in Suit::iterator() {
+        let mut cards = missing(natural, Rank::iterator().rev().map(|r| Card::new(*r, *suit)));
+        cards.truncate(wild_count);
+        choices.push(cards);
+    }
+    // Straights from Ace high down to the wheel, where the Ace plays low
+    let held = natural.iter().fold(0u32, |mask, c| mask | (1 << c.rank.ordinal()));
+    for top in (3..13).rev() {
+        let ranks: Vec<Rank> = (top - 4..=top).map(|r: i32| Rank::ranks()[r.rem_euclid(13) as usize]).collect();
+        let straight: Vec<Card> = ranks.iter()
+            .filter(|r| held & (1 << r.ordinal()) == 0)
+            .map(|r| Card::new(*r, Suit::Spades))
+            .collect();
+        if straight.len() <= wild_count {
+            choices.push(straight);
+        }
+        for suit in Suit::iterator() {
+            let straight_flush = missing(natural, ranks.iter().map(|r| Card::new(*r, *suit)));
+            if straight_flush.len() <= wild_count {
+                choices.push(straight_flush);
+            }
+        }
+    }
+    choices
+}